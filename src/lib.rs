@@ -22,8 +22,35 @@
 //! assert_eq!(Some("jpeg"), extension);
 //! ```
 
+#[cfg(feature = "memchr")]
+extern crate memchr;
+#[cfg(feature = "aho-corasick")]
+extern crate aho_corasick;
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Deref, DerefMut, Range};
+use std::str::FromStr;
+
 /// Describes a type that can be cheaply converted into a [`StrSlicer`].
 ///
+/// Both methods borrow `self` the same way, so generic code can freely use either of them:
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// use slicer::trackers::LineTracker;
+///
+/// fn first_word_and_lines<'str>(source: impl AsSlicer<'str>) -> (Option<&'str str>, usize) {
+///     let word = source.as_slicer().slice_non_whitespace();
+///     let mut slicer = source.as_slicer_with_tracker(LineTracker::new());
+///     slicer.skip_to_end();
+///     (word, slicer.tracker_pos())
+/// }
+///
+/// assert_eq!(first_word_and_lines("Hello\nworld"), (Some("Hello"), 1));
+/// ```
+///
 /// [`StrSlicer`]: struct.StrSlicer.html
 pub trait AsSlicer<'str> {
     /// Converts the type to a [`StrSlicer`].
@@ -49,7 +76,24 @@ pub trait AsSlicer<'str> {
     /// ```
     ///
     /// [`Tracker`]: trait.Tracker.html
-    fn as_slicer_with_tracker<T: Tracker>(&'str self, tracker: T) -> StrSlicer<'str, T>;
+    fn as_slicer_with_tracker<T: Tracker>(&self, tracker: T) -> StrSlicer<'str, T>;
+    /// Converts the type to a slicer with a [`Tracker`] created with `Default::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "a\nb".as_slicer_tracked::<LineTracker>();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    /// ```
+    ///
+    /// [`Tracker`]: trait.Tracker.html
+    fn as_slicer_tracked<T: Tracker + Default>(&self) -> StrSlicer<'str, T> {
+        self.as_slicer_with_tracker(T::default())
+    }
 }
 impl<'str> AsSlicer<'str> for &'str str {
     fn as_slicer(&self) -> StrSlicer<'str> {
@@ -60,8 +104,60 @@ impl<'str> AsSlicer<'str> for &'str str {
     }
 }
 
+/// Creates a [`StrSlicer`] from the given string slice, without needing to import [`AsSlicer`].
+///
+/// # Examples
+///
+/// ```
+/// let mut slicer = slicer::from("path/to/file");
+/// assert_eq!(slicer.slice_until('/'), Some("path"));
+/// ```
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`AsSlicer`]: trait.AsSlicer.html
+pub fn from(string: &str) -> StrSlicer<'_> {
+    StrSlicer::new(string)
+}
+/// Creates a [`StrSlicer`] from the given string slice and [`Tracker`], without needing to import [`AsSlicer`].
+///
+/// # Examples
+///
+/// ```
+/// use slicer::trackers::LineTracker;
+///
+/// let mut slicer = slicer::from_with_tracker("a\nb", LineTracker::new());
+/// slicer.skip_to_end();
+/// assert_eq!(slicer.tracker_pos(), 1);
+/// ```
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`Tracker`]: trait.Tracker.html
+/// [`AsSlicer`]: trait.AsSlicer.html
+pub fn from_with_tracker<T: Tracker>(string: &str, tracker: T) -> StrSlicer<'_, T> {
+    StrSlicer::with_tracker(string, tracker)
+}
+
 /// Describes a type that tracks information as the [`StrSlicer`] goes through the string.
 ///
+/// # Examples
+///
+/// ```
+/// use slicer::{StrSlicer, Tracker};
+///
+/// struct JumpCounter(usize);
+/// impl Tracker for JumpCounter {
+///     type Pos = usize;
+///     fn pos(&self) -> usize { self.0 }
+///     fn update(&mut self, _string: &str, _old_byte_pos: usize, _new_byte_pos: usize) { self.0 += 1; }
+/// }
+///
+/// let mut slicer = StrSlicer::with_tracker("a,b", JumpCounter(0));
+/// let start = slicer.save_pos();
+/// slicer.skip_until(',');
+/// slicer.load_pos(start);
+/// assert_eq!(slicer.tracker_pos(), 2);
+/// ```
+///
 /// [`StrSlicer`]: struct.StrSlicer.html
 pub trait Tracker {
     /// Type of the position returned from [`StrSlicer::tracker_pos`].
@@ -78,16 +174,58 @@ pub trait Tracker {
     /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
     /// [`jump_to_unchecked`]: struct.StrSlicer.html#method.jump_to_unchecked
     fn update(&mut self, string: &str, old_byte_pos: usize, new_byte_pos: usize);
+    /// Resets the tracker to the state it would be in at the start of a new string.
+    /// Called by [`StrSlicer::retarget`].
+    ///
+    /// The default implementation does nothing, so trackers that hold any state should override it.
+    ///
+    /// [`StrSlicer::retarget`]: struct.StrSlicer.html#method.retarget
+    fn reset(&mut self) {}
 }
 /// Allows the `()` type to be used as a null tracker, that doesn't do anything.
 impl Tracker for () {
     type Pos = ();
-    fn pos(&self) -> Self::Pos {
-        ()
-    }
+    fn pos(&self) -> Self::Pos {}
     fn update(&mut self, _string: &str, _old_byte_pos: usize, _new_byte_pos: usize) {}
 }
 
+/// Describes a [`Tracker`] that can cheaply save and load its state, which lets [`StrSlicer::bookmark`]
+/// and [`StrSlicer::restore`] be used with it.
+///
+/// Restoring a bookmark loads the saved state instead of recomputing it with [`Tracker::update`],
+/// so going back to a bookmark takes constant time. Trackers that don't implement this can still
+/// go back to a saved position with [`StrSlicer::save_pos`] and [`StrSlicer::load_pos`].
+///
+/// [`Tracker`]: trait.Tracker.html
+/// [`Tracker::update`]: trait.Tracker.html#tymethod.update
+/// [`StrSlicer::bookmark`]: struct.StrSlicer.html#method.bookmark
+/// [`StrSlicer::restore`]: struct.StrSlicer.html#method.restore
+/// [`StrSlicer::save_pos`]: struct.StrSlicer.html#method.save_pos
+/// [`StrSlicer::load_pos`]: struct.StrSlicer.html#method.load_pos
+pub trait SnapshotTracker: Tracker {
+    /// Type of the state saved by [`snapshot`] and loaded by [`restore`].
+    ///
+    /// [`snapshot`]: trait.SnapshotTracker.html#tymethod.snapshot
+    /// [`restore`]: trait.SnapshotTracker.html#tymethod.restore
+    type Snapshot;
+    /// Saves the tracker's current state. Called by [`StrSlicer::bookmark`].
+    ///
+    /// [`StrSlicer::bookmark`]: struct.StrSlicer.html#method.bookmark
+    fn snapshot(&self) -> Self::Snapshot;
+    /// Loads a state previously saved with [`snapshot`]. Called by [`StrSlicer::restore`],
+    /// instead of [`Tracker::update`].
+    ///
+    /// [`snapshot`]: trait.SnapshotTracker.html#tymethod.snapshot
+    /// [`Tracker::update`]: trait.Tracker.html#tymethod.update
+    /// [`StrSlicer::restore`]: struct.StrSlicer.html#method.restore
+    fn restore(&mut self, snapshot: Self::Snapshot);
+}
+impl SnapshotTracker for () {
+    type Snapshot = ();
+    fn snapshot(&self) -> Self::Snapshot {}
+    fn restore(&mut self, _snapshot: Self::Snapshot) {}
+}
+
 /// Describes a type that can be used as an input to many of [`StrSlicer`]'s methods.
 ///
 /// [`StrSlicer`]: struct.StrSlicer.html
@@ -111,11 +249,113 @@ pub trait Pattern {
     ///
     /// See [`StrSlicer::skip_over`] for more details.
     ///
+    /// # Safety
+    ///
+    /// The pattern must actually be next in the slicer, otherwise the slicer may be
+    /// left on a position that is not a UTF-8 code point boundary.
+    ///
     /// [`StrSlicer`]: struct.StrSlicer.html
     /// [`StrSlicer::skip_over`]: struct.StrSlicer.html#method.skip_over
     unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>);
+    /// Steps the given [`StrSlicer`] backwards to the start of the last occurrence of this pattern
+    /// that lies entirely before its current position, or to the start of the string if there is none.
+    ///
+    /// The default implementation walks backwards one char at a time, checking [`is_next`] at each
+    /// position, so implementors that can search backwards faster should override it.
+    ///
+    /// See [`StrSlicer::rskip_until_after`] for more details.
+    ///
+    /// [`StrSlicer`]: struct.StrSlicer.html
+    /// [`is_next`]: trait.Pattern.html#tymethod.is_next
+    /// [`StrSlicer::rskip_until_after`]: struct.StrSlicer.html#method.rskip_until_after
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        //only look at the string behind the slicer, so that matches can't reach past its position
+        let mut probe = StrSlicer::new(&slicer.string[..slicer.byte_pos]);
+        let mut byte_pos = slicer.byte_pos;
+        loop {
+            probe.byte_pos = byte_pos;
+            if byte_pos == 0 || self.is_next(&probe) {
+                break;
+            }
+            byte_pos -= 1;
+            while !probe.string.is_char_boundary(byte_pos) {
+                byte_pos -= 1;
+            }
+        }
+        unsafe {
+            slicer.jump_to_unchecked(byte_pos);
+        }
+    }
+    /// Returns the byte length of this pattern's match at the given [`StrSlicer`]'s current position,
+    /// or `None` if the pattern isn't next. Used by [`StrSlicer::skip_over`] to jump over a match directly.
+    ///
+    /// The default implementation checks [`is_next`] and then skips over the pattern on a copy of the slicer,
+    /// so implementors that find out the length while checking whether they're next should override it.
+    ///
+    /// # Examples
+    ///
+    /// A pattern for a run of ASCII digits, whose match length depends on the input:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::{Pattern, StrSlicer, Tracker};
+    ///
+    /// struct Digits;
+    /// impl Pattern for Digits {
+    ///     fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+    ///         self.match_len(slicer).is_some()
+    ///     }
+    ///     fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+    ///         slicer.skip_until(|c: char| c.is_ascii_digit());
+    ///     }
+    ///     unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+    ///         slicer.skip_until(|c: char| !c.is_ascii_digit());
+    ///     }
+    ///     fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+    ///         let len = slicer.cut_off()?.bytes().take_while(u8::is_ascii_digit).count();
+    ///         if len > 0 { Some(len) } else { None }
+    ///     }
+    /// }
+    ///
+    /// let mut slicer = "x=1234;".as_slicer();
+    /// slicer.skip_over("x=");
+    /// assert_eq!(slicer.skip_over_len(Digits), Some(4));
+    /// assert_eq!(slicer.skip_over(Digits), false);
+    /// assert_eq!(slicer.cut_off(), Some(";"));
+    /// ```
+    ///
+    /// [`StrSlicer`]: struct.StrSlicer.html
+    /// [`StrSlicer::skip_over`]: struct.StrSlicer.html#method.skip_over
+    /// [`is_next`]: trait.Pattern.html#tymethod.is_next
+    fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+        if self.is_next(slicer) {
+            let mut probe = slicer.probe();
+            unsafe {
+                self.skip_over_unchecked(&mut probe);
+            }
+            Some(probe.byte_pos - slicer.byte_pos)
+        } else {
+            None
+        }
+    }
 }
-impl<'a> Pattern for &'a str {
+/// Allows string slices to be used as patterns.
+///
+/// An empty string slice is never next, so that loops like [`StrSlicer::skip_over_all`] can't get stuck
+/// on it. Skipping until it behaves like skipping until a pattern that isn't found. Empty byte slices
+/// behave the same way.
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut slicer = "abc".as_slicer();
+/// assert_eq!(slicer.is_next(""), false);
+/// assert_eq!(slicer.skip_over(""), false);
+/// assert_eq!(slicer.skip_over_all(""), 0);
+/// assert_eq!(slicer.slice_until(""), Some("abc"));
+/// ```
+///
+/// [`StrSlicer::skip_over_all`]: struct.StrSlicer.html#method.skip_over_all
+impl Pattern for &str {
     fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
         /*let start_pos = slicer.byte_pos();
         let end_pos = start_pos + self.len();
@@ -126,7 +366,7 @@ impl<'a> Pattern for &'a str {
         }*/
         match slicer.cut_off() {
             None => false,
-            Some(cut_off) => cut_off.starts_with(*self)
+            Some(cut_off) => !self.is_empty() && cut_off.starts_with(*self)
         }
     }
     fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
@@ -134,7 +374,11 @@ impl<'a> Pattern for &'a str {
             None => return, //return early, since the slicer is finished so there's nothing we can do
             Some(cut_off) => cut_off
         };
-        match cut_off.find(*self) {
+        //return early if the pattern is already next, without searching the rest of the string
+        if !self.is_empty() && cut_off.starts_with(*self) {
+            return;
+        }
+        match cut_off.find(*self).filter(|_| !self.is_empty()) {
             //if this pattern was not found in the string, simulate skipping until the end of the string
             None => slicer.skip_to_end(),
             //if the pattern was found, jump to it
@@ -148,12 +392,28 @@ impl<'a> Pattern for &'a str {
     }
     unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
         let byte_pos = slicer.byte_pos;
-        slicer.jump_to_unchecked(byte_pos + self.len());
+        let new_byte_pos = byte_pos.wrapping_add(self.len());
+        debug_assert!(new_byte_pos >= byte_pos && slicer.string.is_char_boundary(new_byte_pos),
+                      "skipping over {:?} at byte index {} leaves the slicer out of bounds or inside a char", self, byte_pos);
+        slicer.jump_to_unchecked(new_byte_pos);
+    }
+    fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+        if self.is_next(slicer) {
+            Some(self.len())
+        } else {
+            None
+        }
+    }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let byte_pos = slicer.string[..slicer.byte_pos].rfind(*self).filter(|_| !self.is_empty()).unwrap_or(0);
+        unsafe {
+            slicer.jump_to_unchecked(byte_pos);
+        }
     }
 }
 impl Pattern for char {
     fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
-        match slicer.as_str().chars().next() {
+        match slicer.cut_off().and_then(|cut_off| cut_off.chars().next()) {
             Some(char) => *self == char,
             None => false
         }
@@ -163,6 +423,10 @@ impl Pattern for char {
             None => return, //return early, since the slicer is finished so there's nothing we can do
             Some(cut_off) => cut_off
         };
+        //return early if the pattern is already next, without searching the rest of the string
+        if cut_off.starts_with(*self) {
+            return;
+        }
         match cut_off.find(*self) {
             //if this pattern was not found in the string, simulate skipping until the end of the string
             None => slicer.skip_to_end(),
@@ -179,10 +443,273 @@ impl Pattern for char {
         let byte_pos = slicer.byte_pos;
         slicer.jump_to_unchecked(byte_pos + self.len_utf8());
     }
+    fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+        if self.is_next(slicer) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let byte_pos = slicer.string[..slicer.byte_pos].rfind(*self).unwrap_or(0);
+        unsafe {
+            slicer.jump_to_unchecked(byte_pos);
+        }
+    }
+}
+/// Allows a `Cow<str>` to be used as a pattern, behaving the same as the string slice it holds.
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// use std::borrow::Cow;
+///
+/// let mut slicer = "a::b=>c".as_slicer();
+/// slicer.skip_until(Cow::Borrowed("::"));
+/// assert_eq!(slicer.cut_off(), Some("::b=>c"));
+///
+/// let arrow: Cow<str> = Cow::Owned(String::from("=>"));
+/// slicer.skip_until(&arrow);
+/// assert_eq!(slicer.cut_off(), Some("=>c"));
+/// ```
+impl<'a> Pattern for Cow<'a, str> {
+    fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+        (&**self).is_next(slicer)
+    }
+    fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&**self).skip_until(slicer)
+    }
+    unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&**self).skip_over_unchecked(slicer)
+    }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&**self).rskip_until_after(slicer)
+    }
+    fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+        (&**self).match_len(slicer)
+    }
+}
+/// Allows a reference to a `Cow<str>` to be used as a pattern, behaving the same as the string slice it holds.
+impl<'a, 'b> Pattern for &'b Cow<'a, str> {
+    fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+        (&***self).is_next(slicer)
+    }
+    fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&***self).skip_until(slicer)
+    }
+    unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&***self).skip_over_unchecked(slicer)
+    }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        (&***self).rskip_until_after(slicer)
+    }
+    fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+        (&***self).match_len(slicer)
+    }
+}
+/// Allows raw bytes to be used as patterns, which is useful when a delimiter is defined in terms of bytes.
+///
+/// The slicer always stays on a UTF-8 code point boundary, so if the bytes are found starting or
+/// ending in the middle of a code point, skipping until or over them moves forward to the next
+/// code point boundary instead. Patterns made of ASCII bytes never run into this.
+///
+/// Like an empty string slice, an empty byte slice is never next, and skipping until it
+/// behaves like skipping until a pattern that isn't found.
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut slicer = "key--value".as_slicer();
+/// assert_eq!(slicer.slice_until(&b"--"[..]), Some("key"));
+/// assert_eq!(slicer.skip_over(&b"--"[..]), true);
+/// assert_eq!(slicer.cut_off(), Some("value"));
+///
+/// let mut slicer = "abc".as_slicer();
+/// assert_eq!(slicer.is_next(&b""[..]), false);
+/// assert_eq!(slicer.skip_over_all(&b""[..]), 0);
+/// assert_eq!(slicer.slice_until(&b""[..]), Some("abc"));
+/// ```
+impl Pattern for &[u8] {
+    fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+        match slicer.cut_off() {
+            None => false,
+            Some(cut_off) => !self.is_empty() && cut_off.as_bytes().starts_with(self)
+        }
+    }
+    fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let cut_off = match slicer.cut_off() {
+            None => return, //return early, since the slicer is finished so there's nothing we can do
+            Some(cut_off) => cut_off
+        };
+        let found = if self.is_empty() {
+            None
+        } else {
+            cut_off.as_bytes().windows(self.len()).position(|window| window == *self)
+        };
+        match found {
+            //if this pattern was not found in the string, simulate skipping until the end of the string
+            None => slicer.skip_to_end(),
+            //if the pattern was found, jump to the first code point boundary at or after it
+            Some(offset) => {
+                let mut byte_pos = slicer.byte_pos + offset;
+                while !slicer.string.is_char_boundary(byte_pos) {
+                    byte_pos += 1;
+                }
+                unsafe {
+                    slicer.jump_to_unchecked(byte_pos);
+                }
+            }
+        }
+    }
+    unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let mut byte_pos = (slicer.byte_pos + self.len()).min(slicer.end_byte_pos());
+        while !slicer.string.is_char_boundary(byte_pos) {
+            byte_pos += 1;
+        }
+        slicer.jump_to_unchecked(byte_pos);
+    }
+}
+/// Allows a set of chars to be used as a pattern that matches any single one of them.
+///
+/// Searching is delegated to `str::find`, which is faster than the equivalent closure
+/// (such as `|c| c == ',' || c == ';'`) for literal sets of chars.
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let separators = &[',', ';', '|'][..];
+/// let mut slicer = "a|b;c,d".as_slicer();
+/// assert_eq!(slicer.slice_until(separators), Some("a"));
+/// assert_eq!(slicer.skip_over(separators), true);
+/// assert_eq!(slicer.slice_until(separators), Some("b"));
+/// ```
+impl Pattern for &[char] {
+    fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+        match slicer.cut_off().and_then(|cut_off| cut_off.chars().next()) {
+            Some(char) => self.contains(&char),
+            None => false
+        }
+    }
+    fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let cut_off = match slicer.cut_off() {
+            None => return, //return early, since the slicer is finished so there's nothing we can do
+            Some(cut_off) => cut_off
+        };
+        //return early if the pattern is already next, without searching the rest of the string
+        if cut_off.starts_with(*self) {
+            return;
+        }
+        match cut_off.find(*self) {
+            //if this pattern was not found in the string, simulate skipping until the end of the string
+            None => slicer.skip_to_end(),
+            //if the pattern was found, jump to it
+            Some(offset) => {
+                let byte_pos = slicer.byte_pos;
+                unsafe {
+                    slicer.jump_to_unchecked(byte_pos + offset);
+                }
+            }
+        }
+    }
+    unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        slicer.advance_char();
+    }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let byte_pos = slicer.string[..slicer.byte_pos].rfind(*self).unwrap_or(0);
+        unsafe {
+            slicer.jump_to_unchecked(byte_pos);
+        }
+    }
+}
+/// Allows a list of string slices to be used as a pattern that matches any one of them.
+///
+/// Skipping until the pattern stops at the earliest place where any of the string slices is next.
+/// If several of them are next at the same place, the first one in the list is the one that gets skipped over,
+/// so longer alternatives should be listed before their prefixes.
+/// Empty string slices in the list are ignored, since an empty string slice is never next on its own.
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let newlines = &["\r\n", "\n"][..];
+/// let mut slicer = "a\r\nb\nc".as_slicer();
+/// assert_eq!(slicer.slice_until(newlines), Some("a"));
+/// assert_eq!(slicer.skip_over(newlines), true);
+/// assert_eq!(slicer.slice_until(newlines), Some("b"));
+/// assert_eq!(slicer.skip_over(newlines), true);
+/// assert_eq!(slicer.cut_off(), Some("c"));
+/// ```
+impl Pattern for &[&str] {
+    fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+        match slicer.cut_off() {
+            None => false,
+            Some(cut_off) => self.iter().any(|s| !s.is_empty() && cut_off.starts_with(s))
+        }
+    }
+    fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let cut_off = match slicer.cut_off() {
+            None => return, //return early, since the slicer is finished so there's nothing we can do
+            Some(cut_off) => cut_off
+        };
+        match self.iter().filter(|s| !s.is_empty()).filter_map(|s| cut_off.find(s)).min() {
+            //if none of the string slices were found, simulate skipping until the end of the string
+            None => slicer.skip_to_end(),
+            //if any of them were found, jump to the earliest one
+            Some(offset) => {
+                let byte_pos = slicer.byte_pos;
+                unsafe {
+                    slicer.jump_to_unchecked(byte_pos + offset);
+                }
+            }
+        }
+    }
+    unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let len = match slicer.cut_off() {
+            None => return,
+            Some(cut_off) => self.iter().find(|s| !s.is_empty() && cut_off.starts_with(*s)).map_or(0, |s| s.len())
+        };
+        let byte_pos = slicer.byte_pos;
+        slicer.jump_to_unchecked(byte_pos + len);
+    }
 }
+/// Allows closures, named functions and function pointers that take a `char` and return a
+/// `bool` to be used as patterns that match a single char.
+///
+/// Function pointers are already covered by this implementation, so named functions can be
+/// passed directly without wrapping them in a closure:
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// fn is_digit_char(c: char) -> bool {
+///     c.is_ascii_digit()
+/// }
+///
+/// let mut slicer = "abc123".as_slicer();
+/// slicer.skip_until(is_digit_char);
+/// assert_eq!(slicer.cut_off(), Some("123"));
+///
+/// let predicate: fn(char) -> bool = char::is_alphabetic;
+/// let mut slicer = "123abc".as_slicer();
+/// slicer.skip_until(predicate);
+/// assert_eq!(slicer.cut_off(), Some("abc"));
+/// ```
+///
+/// Trait objects are covered as well, since `&mut dyn FnMut(char) -> bool` and
+/// `Box<dyn FnMut(char) -> bool>` implement `FnMut(char) -> bool` themselves,
+/// so predicates picked at runtime can be used too:
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut predicates: Vec<Box<dyn FnMut(char) -> bool>> = vec![
+///     Box::new(|c| c == ','),
+///     Box::new(char::is_whitespace)
+/// ];
+///
+/// let mut slicer = "a,b c".as_slicer();
+/// let predicate: &mut dyn FnMut(char) -> bool = &mut predicates[0];
+/// assert_eq!(slicer.slice_until(predicate), Some("a"));
+/// slicer.skip_over(',');
+/// assert_eq!(slicer.slice_until(&mut predicates[1]), Some("b"));
+/// ```
 impl<F: FnMut(char) -> bool> Pattern for F {
     fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
-        match slicer.as_str().chars().next() {
+        match slicer.cut_off().and_then(|cut_off| cut_off.chars().next()) {
             Some(char) => self(char),
             None => false
         }
@@ -192,6 +719,8 @@ impl<F: FnMut(char) -> bool> Pattern for F {
             None => return, //return early, since the slicer is finished so there's nothing we can do
             Some(cut_off) => cut_off
         };
+        //unlike for the `&str` and `char` patterns, there's no early return for when the pattern is already next:
+        //`find` checks the first char first anyway, and checking it separately would call the predicate twice
         match cut_off.find(self) {
             //if this pattern was not found in the string, simulate skipping until the end of the string
             None => slicer.skip_to_end(),
@@ -207,6 +736,12 @@ impl<F: FnMut(char) -> bool> Pattern for F {
     unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
         slicer.advance_char();
     }
+    fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+        let byte_pos = slicer.string[..slicer.byte_pos].rfind(self).unwrap_or(0);
+        unsafe {
+            slicer.jump_to_unchecked(byte_pos);
+        }
+    }
 }
 
 /// A string slicer.
@@ -219,6 +754,7 @@ impl<F: FnMut(char) -> bool> Pattern for F {
 pub struct StrSlicer<'str, T: Tracker = ()> {
     string: &'str str,
     byte_pos: usize,
+    scan_limit: Option<usize>,
     tracker: T
 }
 impl<'str> StrSlicer<'str, ()> {
@@ -238,6 +774,73 @@ impl<'str> StrSlicer<'str, ()> {
         Self {
             string,
             byte_pos: 0,
+            scan_limit: None,
+            tracker: ()
+        }
+    }
+    /// Creates a `StrSlicer` that only operates on the given byte range of the string slice.
+    ///
+    /// The slicer never sees anything outside of the range, and all of its byte indices are relative to the start of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or doesn't start and end on UTF-8 code point boundaries,
+    /// the same way slicing the string slice with it would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::StrSlicer;
+    /// let mut slicer = StrSlicer::from_range("images/cat.jpeg", 7..10);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// assert_eq!(slicer.slice_to_end(), Some("cat"));
+    /// ```
+    pub fn from_range(string: &'str str, range: Range<usize>) -> Self {
+        Self::new(&string[range])
+    }
+    
+    /// Removes `open` from the start and `close` from the end of the given string slice, if it
+    /// both starts with `open` and ends with `close`. Otherwise the string slice is returned unchanged.
+    ///
+    /// Useful for stripping the quotes or brackets from a field that was sliced out earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::StrSlicer;
+    /// assert_eq!(StrSlicer::trim_pair("(x)", '(', ')'), "x");
+    /// assert_eq!(StrSlicer::trim_pair("x)", '(', ')'), "x)");
+    /// assert_eq!(StrSlicer::trim_pair("\"", '"', '"'), "\"");
+    /// ```
+    pub fn trim_pair(s: &str, open: char, close: char) -> &str {
+        if s.len() >= open.len_utf8() + close.len_utf8() && s.starts_with(open) && s.ends_with(close) {
+            &s[open.len_utf8()..(s.len() - close.len_utf8())]
+        } else {
+            s
+        }
+    }
+    /// Creates a [`SlicerBuilder`] for configuring a slicer over the given string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::StrSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = StrSlicer::builder("header\nbody")
+    ///     .tracker(LineTracker::new())
+    ///     .offset(7)
+    ///     .build();
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    /// assert_eq!(slicer.slice_to_end(), Some("body"));
+    /// ```
+    ///
+    /// [`SlicerBuilder`]: struct.SlicerBuilder.html
+    pub fn builder(string: &'str str) -> SlicerBuilder<'str> {
+        SlicerBuilder {
+            string,
+            offset: 0,
+            scan_limit: None,
             tracker: ()
         }
     }
@@ -260,11 +863,30 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     /// [`Tracker`]: trait.Tracker.html
     pub fn with_tracker(string: &'str str, tracker: T) -> Self {
         Self {
-            string: string,
+            string,
             byte_pos: 0,
+            scan_limit: None,
             tracker
         }
     }
+    /// Creates a `StrSlicer` from the given string slice, with a [`Tracker`] created with `Default::default()`.
+    ///
+    /// You should prefer to use [`AsSlicer::as_slicer_tracked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::StrSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer: StrSlicer<LineTracker> = StrSlicer::with_default_tracker("This string is being turned into a string slicer.");
+    /// ```
+    ///
+    /// [`AsSlicer::as_slicer_tracked`]: trait.AsSlicer.html#method.as_slicer_tracked
+    /// [`Tracker`]: trait.Tracker.html
+    pub fn with_default_tracker(string: &'str str) -> Self where T: Default {
+        Self::with_tracker(string, T::default())
+    }
     
     fn next_char_boundary(&self) -> Option<usize> {
         let mut next_byte_pos = self.byte_pos + 1;
@@ -291,6 +913,85 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     fn end_byte_pos(&self) -> usize {
         self.string.len()
     }
+    /// Returns the byte index that a search from the current position may not go past,
+    /// which is the scan limit rounded down to a char boundary, or the end of the string.
+    fn limit_byte_pos(&self) -> usize {
+        match self.scan_limit {
+            Some(limit) if self.byte_pos.saturating_add(limit) < self.end_byte_pos() => {
+                let mut limit_pos = self.byte_pos + limit;
+                while !self.string.is_char_boundary(limit_pos) {
+                    limit_pos -= 1;
+                }
+                limit_pos
+            },
+            _ => self.end_byte_pos()
+        }
+    }
+    /// Skips forward until the given pattern is next, without going further than the scan limit
+    /// allows. Returns `true` if the slicer was stopped by the scan limit.
+    fn scan_until<P: Pattern>(&mut self, pattern: &mut P) -> bool {
+        let start_pos = self.byte_pos;
+        let limit_pos = self.limit_byte_pos();
+        //without a limit, or with one past the end of the string, there's nothing to keep the search from
+        if limit_pos >= self.end_byte_pos() {
+            pattern.skip_until(self);
+            return false;
+        }
+        
+        //search an untracked copy that can't see past the limit, so that neither the search nor the
+        //tracker ever go further than the limit allows
+        let mut limited = StrSlicer::new(&self.string[..limit_pos]);
+        limited.byte_pos = start_pos;
+        let mut found_pos = None;
+        loop {
+            pattern.skip_until(&mut limited);
+            if limited.byte_pos >= limit_pos {
+                break;
+            }
+            //patterns that look at what follows their match may have been fooled by the cut off end, so make sure
+            //the match is also there in the whole string
+            let mut probe = self.probe();
+            probe.byte_pos = limited.byte_pos;
+            if pattern.is_next(&probe) {
+                found_pos = Some(limited.byte_pos);
+                break;
+            }
+            limited.advance_char();
+        }
+        //a match that starts right at the limit is still within it
+        let found_pos = found_pos.or_else(|| {
+            let mut probe = self.probe();
+            probe.byte_pos = limit_pos;
+            if pattern.is_next(&probe) { Some(limit_pos) } else { None }
+        });
+        
+        unsafe {
+            self.jump_to_unchecked(found_pos.unwrap_or(limit_pos));
+        }
+        found_pos.is_none()
+    }
+    /// Creates an untracked copy of this slicer at the same position, for looking ahead without moving.
+    fn probe(&self) -> StrSlicer<'str> {
+        let mut probe = StrSlicer::new(self.string);
+        probe.byte_pos = self.byte_pos;
+        probe
+    }
+    /// Skips forward until the given pattern is next and returns `true`, or returns `false`
+    /// and jumps back to where the slicer started if the pattern isn't found.
+    fn skip_until_found<P: Pattern>(&mut self, pattern: &mut P) -> bool {
+        //search on an untracked copy, so the tracker only sees the slicer move if the pattern is found
+        let mut probe = self.probe();
+        probe.scan_limit = self.scan_limit;
+        probe.scan_until(pattern);
+        if pattern.is_next(&probe) {
+            unsafe {
+                self.jump_to_unchecked(probe.byte_pos);
+            }
+            true
+        } else {
+            false
+        }
+    }
     
     /// Returns a reference to the string slice that this slicer is operating on.
     ///
@@ -312,6 +1013,221 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     pub fn as_str(&self) -> &'str str {
         self.string
     }
+    /// Returns the part of the string this slicer is operating on within the given byte range, without moving.
+    ///
+    /// Useful for turning byte indices saved earlier, such as from [`byte_pos`], back into a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let slicer = "images/cat".as_slicer();
+    /// assert_eq!(slicer.substr(0..6), "images");
+    /// ```
+    ///
+    /// Like with [`jump_to`], byte indices that are out of bounds or in the middle of a UTF-8 code point panic.
+    /// This example panics:
+    ///
+    /// ```should_panic
+    /// # use slicer::AsSlicer;
+    /// let slicer = "🌺 is a hibiscus.".as_slicer();
+    /// slicer.substr(0..2);
+    /// ```
+    ///
+    /// [`byte_pos`]: struct.StrSlicer.html#method.byte_pos
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    pub fn substr(&self, range: Range<usize>) -> &'str str {
+        for &byte_pos in &[range.start, range.end] {
+            if byte_pos > self.end_byte_pos() {
+                jump_oob_fail(self.string, byte_pos);
+            }
+            if !self.string.is_char_boundary(byte_pos) {
+                jump_char_boundary_fail(self.string, byte_pos);
+            }
+        }
+        if range.start > range.end {
+            panic!("byte range starts at {} but ends at {}", range.start, range.end);
+        }
+        &self.string[range]
+    }
+    /// Returns the number of lines in the whole string this slicer is operating on,
+    /// regardless of the slicer's position or tracker.
+    ///
+    /// Lines are counted the same way as `str::lines`, so a trailing newline doesn't count as an extra empty line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// assert_eq!("a\nb\nc".as_slicer().line_count(), 3);
+    /// assert_eq!("a\nb\n".as_slicer().line_count(), 2);
+    /// ```
+    pub fn line_count(&self) -> usize {
+        self.string.lines().count()
+    }
+    /// Returns the number of chars in the whole string this slicer is operating on, regardless of the slicer's position.
+    ///
+    /// This walks over the whole string, so it takes time proportional to the string's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let slicer = "🌺ab".as_slicer();
+    /// assert_eq!(slicer.char_len(), 3);
+    /// assert_eq!(slicer.as_str().len(), 6);
+    /// ```
+    pub fn char_len(&self) -> usize {
+        self.string.chars().count()
+    }
+    /// Returns the number of lines left in the rest of the string, counting the line the slicer is currently on.
+    ///
+    /// Lines are counted the same way as [`line_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a\nb\nc".as_slicer();
+    /// assert_eq!(slicer.num_remaining_lines(), 3);
+    /// slicer.skip_line();
+    /// assert_eq!(slicer.num_remaining_lines(), 2);
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.num_remaining_lines(), 0);
+    ///
+    /// let text = "one\ntwo\r\nthree\n";
+    /// assert_eq!(text.as_slicer().num_remaining_lines(), text.lines().count());
+    /// ```
+    ///
+    /// With the `memchr` feature enabled, newlines are searched for with the `memchr` crate,
+    /// which is much faster on long strings. The results are the same either way.
+    ///
+    /// [`line_count`]: struct.StrSlicer.html#method.line_count
+    pub fn num_remaining_lines(&self) -> usize {
+        match self.cut_off() {
+            None => 0,
+            //every newline ends a line, and there's one more line after the last newline unless it's at the very end
+            Some(cut_off) => count_newlines(cut_off).0 + if cut_off.ends_with('\n') { 0 } else { 1 }
+        }
+    }
+    /// Returns the 1-based line and column numbers of this slicer's position, with columns counted in chars.
+    ///
+    /// This is computed from the string each time it's called, regardless of the slicer's tracker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "ab\n🌺x".as_slicer();
+    /// assert_eq!(slicer.line_and_column(), (1, 1));
+    /// slicer.skip_until('x');
+    /// assert_eq!(slicer.line_and_column(), (2, 2));
+    /// ```
+    pub fn line_and_column(&self) -> (usize, usize) {
+        self.line_and_column_with_tabs(1)
+    }
+    /// Same as [`line_and_column`], except that a tab moves the column to the next multiple of `tab_width`,
+    /// like in most editors and terminals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "\tx".as_slicer();
+    /// slicer.skip_over('\t');
+    /// assert_eq!(slicer.line_and_column_with_tabs(4), (1, 5));
+    /// assert_eq!(slicer.line_and_column(), (1, 2));
+    /// ```
+    ///
+    /// [`line_and_column`]: struct.StrSlicer.html#method.line_and_column
+    pub fn line_and_column_with_tabs(&self, tab_width: usize) -> (usize, usize) {
+        let before = &self.string[..self.byte_pos.min(self.end_byte_pos())];
+        let line_start = before.rfind('\n').map_or(0, |newline_pos| newline_pos + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().fold(0, |column, char| advance_column(column, char, tab_width));
+        (line, column + 1)
+    }
+    /// Returns this slicer's byte index along with its 1-based line and column numbers, as one [`Location`].
+    ///
+    /// The line and column are the same as [`line_and_column`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::Location;
+    ///
+    /// let mut slicer = "ab\ncd".as_slicer();
+    /// slicer.jump_to(4);
+    /// assert_eq!(slicer.location(), Location { byte: 4, line: 2, column: 2 });
+    /// assert_eq!(slicer.location().to_string(), "2:2");
+    /// ```
+    ///
+    /// [`Location`]: struct.Location.html
+    /// [`line_and_column`]: struct.StrSlicer.html#method.line_and_column
+    pub fn location(&self) -> Location {
+        let (line, column) = self.line_and_column();
+        Location {
+            byte: self.byte_pos,
+            line,
+            column
+        }
+    }
+    /// Returns the whole line this slicer is on, without its line terminator and without moving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "first\r\nsecond line\nthird".as_slicer();
+    /// assert_eq!(slicer.current_line(), "first");
+    /// slicer.skip_until("line");
+    /// assert_eq!(slicer.current_line(), "second line");
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.current_line(), "third");
+    /// ```
+    pub fn current_line(&self) -> &'str str {
+        let byte_pos = self.byte_pos.min(self.end_byte_pos());
+        let line_start = self.string[..byte_pos].rfind('\n').map_or(0, |newline_pos| newline_pos + 1);
+        let line_end = self.string[byte_pos..].find('\n').map_or(self.end_byte_pos(), |offset| byte_pos + offset);
+        let line = &self.string[line_start..line_end];
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+    /// Formats an error message pointing at this slicer's position, in the style of rustc's errors.
+    ///
+    /// The message is followed by the [`location`], and then the [`current_line`] with a caret under the current column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "key = 1\nname ? 2".as_slicer();
+    /// slicer.skip_until('?');
+    /// let error = slicer.format_error("expected `=`");
+    /// assert_eq!(error, "\
+    /// error: expected `=`
+    ///  --> 2:6
+    ///   |
+    /// 2 | name ? 2
+    ///   |      ^");
+    /// ```
+    ///
+    /// [`location`]: struct.StrSlicer.html#method.location
+    /// [`current_line`]: struct.StrSlicer.html#method.current_line
+    pub fn format_error(&self, message: &str) -> String {
+        let location = self.location();
+        let gutter = " ".repeat(location.line.to_string().len());
+        let byte_pos = self.byte_pos.min(self.end_byte_pos());
+        let line_start = self.string[..byte_pos].rfind('\n').map_or(0, |newline_pos| newline_pos + 1);
+        //keep tabs in the padding, so the caret lines up with the line above it
+        let padding: String = self.string[line_start..byte_pos].chars()
+            .map(|char| if char == '\t' { '\t' } else { ' ' })
+            .collect();
+        format!(
+            "error: {}\n{}--> {}\n{} |\n{} | {}\n{} | {}^",
+            message, gutter, location, gutter, location.line, self.current_line(), gutter, padding
+        )
+    }
     /// Cuts off the end of the string slice at the current position and returns that slice,
     /// without also jumping ahead to the end, as [`slice_to_end`] does.
     ///
@@ -336,6 +1252,99 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
             Some(&self.string[start_pos..end_pos])
         }
     }
+    /// Checks whether or not the rest of the string is exactly `s`, without moving.
+    ///
+    /// Once the slicer is at the end, the rest of the string counts as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a;".as_slicer();
+    /// slicer.skip_over('a');
+    /// assert_eq!(slicer.remaining_eq(";"), true);
+    /// slicer.skip_over(';');
+    /// assert_eq!(slicer.remaining_eq(""), true);
+    /// ```
+    ///
+    /// Unlike [`is_next`], which only checks the start of the rest of the string:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.is_next("ab"), true);
+    /// assert_eq!(slicer.remaining_eq("ab"), false);
+    /// assert_eq!(slicer.remaining_eq("abc"), true);
+    /// ```
+    ///
+    /// [`is_next`]: struct.StrSlicer.html#method.is_next
+    pub fn remaining_eq(&self, s: &str) -> bool {
+        self.cut_off().unwrap_or("") == s
+    }
+    /// Checks whether or not the given [`Pattern`] is next and a single match of it takes up the whole rest of the string,
+    /// without moving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.is_only("abc"), true);
+    /// assert_eq!(slicer.is_only("ab"), false);
+    /// assert_eq!(slicer.is_only(&["ab", "abc"][..]), false);
+    /// assert_eq!(slicer.is_only(&["abc", "ab"][..]), true);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn is_only<P: Pattern>(&self, mut pattern: P) -> bool {
+        let remaining = self.end_byte_pos().saturating_sub(self.byte_pos);
+        pattern.match_len(self) == Some(remaining)
+    }
+    /// Returns the byte at this slicer's position, or `None` if it is at the end of the string.
+    ///
+    /// Since the slicer is always on a UTF-8 code point boundary, this is either an ASCII char or
+    /// the first byte of a multi-byte code point, which makes it cheap to branch on ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.current_byte(), Some(b'a'));
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.current_byte(), None);
+    /// ```
+    pub fn current_byte(&self) -> Option<u8> {
+        self.string.as_bytes().get(self.byte_pos).cloned()
+    }
+    /// Returns a short snippet of the string around the slicer's current position, useful for error messages.
+    ///
+    /// The snippet contains up to `radius` chars before and after the current position, with
+    /// a `‹here›` marker inserted at the position itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abcdef".as_slicer();
+    /// slicer.jump_to(3);
+    /// assert_eq!(slicer.debug_context(2), "bc‹here›de");
+    /// assert_eq!(slicer.debug_context(10), "abc‹here›def");
+    ///
+    /// let mut slicer = "abc".as_slicer();
+    /// unsafe { slicer.jump_to_unchecked(10); }
+    /// assert_eq!(slicer.debug_context(2), "bc‹here›");
+    /// ```
+    pub fn debug_context(&self, radius: usize) -> String {
+        let byte_pos = self.byte_pos.min(self.end_byte_pos());
+        let before = &self.string[..byte_pos];
+        let after = &self.string[byte_pos..];
+        
+        let start_pos = before.char_indices().rev().take(radius).last().map_or(byte_pos, |(index, _)| index);
+        let end_pos = byte_pos + after.char_indices().nth(radius).map_or(after.len(), |(index, _)| index);
+        
+        format!("{}‹here›{}", &self.string[start_pos..byte_pos], &self.string[byte_pos..end_pos])
+    }
     
     /// Gets the slicer's current position in the string as a byte index.
     ///
@@ -351,6 +1360,24 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     pub fn byte_pos(&self) -> usize {
         self.byte_pos
     }
+    /// Gets the byte index of the char at the given char index, counting from the start of the
+    /// string rather than from the slicer's current position. Doesn't move the slicer.
+    ///
+    /// Returns `None` if the string has `char_index` chars or less.
+    ///
+    /// This walks the string from the start, so it is O(n) in `char_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let slicer = "🌺ab".as_slicer();
+    /// assert_eq!(slicer.byte_pos_of_char(1), Some(4));
+    /// assert_eq!(slicer.byte_pos_of_char(9), None);
+    /// ```
+    pub fn byte_pos_of_char(&self, char_index: usize) -> Option<usize> {
+        self.string.char_indices().nth(char_index).map(|(byte_pos, _)| byte_pos)
+    }
     /// Jumps the slicer to the given byte index
     ///
     /// # Panics
@@ -386,6 +1413,47 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
             jump_char_boundary_fail(self.string, byte_pos)
         }
     }
+    /// Jumps forwards or backwards by `delta` bytes from the current position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new position is before the start of the string, beyond the end of the
+    /// string slice, or not on a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "ab\ncd".as_slicer_with_tracker(LineTracker::new());
+    /// slicer.jump_relative(3);
+    /// assert_eq!(slicer.byte_pos(), 3);
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    /// slicer.jump_relative(-2);
+    /// assert_eq!(slicer.byte_pos(), 1);
+    /// assert_eq!(slicer.tracker_pos(), 0);
+    /// ```
+    ///
+    /// Jumping back past the start of the string panics. This example panics:
+    ///
+    /// ```should_panic
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc".as_slicer();
+    /// slicer.jump_relative(1);
+    /// slicer.jump_relative(-2);
+    /// ```
+    pub fn jump_relative(&mut self, delta: isize) {
+        let byte_pos = if delta < 0 {
+            self.byte_pos.checked_sub(delta.unsigned_abs())
+        } else {
+            self.byte_pos.checked_add(delta as usize)
+        };
+        match byte_pos {
+            Some(byte_pos) => self.jump_to(byte_pos),
+            None => panic!("jumping {} bytes from byte index {} is out of bounds", delta, self.byte_pos)
+        }
+    }
     /// Equivalent to [`jump_to`], except without any bounds checking.
     ///
     /// You should almost always prefer to use [`jump_to`].
@@ -405,6 +1473,34 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
         self.tracker.update(string, self.byte_pos, byte_pos);
         self.byte_pos = byte_pos;
     }
+    /// Sets the slicer's position directly, without updating the tracker.
+    ///
+    /// This is meant for performance-critical code that doesn't use a tracker, or that
+    /// keeps the tracker up to date itself. You should almost always prefer to use [`jump_to`].
+    ///
+    /// # Safety
+    ///
+    /// `byte_pos` must be in bounds and on a UTF-8 code point boundary, otherwise the slicer
+    /// will be left in an illegal state and may panic on later method calls. This is
+    /// only checked in debug builds. The tracker will also be out of date after this call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc".as_slicer();
+    /// unsafe {
+    ///     slicer.set_byte_pos_unchecked(2);
+    /// }
+    /// assert_eq!(slicer.cut_off(), Some("c"));
+    /// ```
+    ///
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    pub unsafe fn set_byte_pos_unchecked(&mut self, byte_pos: usize) {
+        debug_assert!(byte_pos <= self.end_byte_pos() && self.string.is_char_boundary(byte_pos),
+                      "byte index {} is out of bounds or inside a char", byte_pos);
+        self.byte_pos = byte_pos;
+    }
     
     /// Returns a reference to this slicer's tracker.
     ///
@@ -446,116 +1542,186 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     pub fn tracker_pos(&self) -> T::Pos {
         self.tracker.pos()
     }
-    
-    //pub fn skip_num_bytes(&mut self, num: usize);
-    //pub fn slice_num_bytes(&mut self, num: usize) -> Option<&'str str>;
-    
-    /// Skips over `num` chars in this slicer's string.
+    /// Gets the position value that this slicer's [`Tracker`] would be tracking at the given byte index,
+    /// without moving this slicer.
     ///
-    /// # Examples
+    /// This jumps a clone of this slicer to `byte_pos`, so it panics in the same cases as [`jump_to`].
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "🌷 Tulip!".as_slicer();
-    /// slicer.skip_num_chars(1);
-    /// assert_eq!(slicer.cut_off(), Some(" Tulip!"));
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let slicer = "Line 1\nLine 2\nLine 3".as_slicer_with_tracker(LineTracker::new());
+    /// assert_eq!(slicer.tracker_pos_at(16), 2);
+    /// assert_eq!(slicer.tracker_pos(), 0);
+    ///
+    /// let mut jumped = slicer.clone();
+    /// jumped.jump_to(16);
+    /// assert_eq!(slicer.tracker_pos_at(16), jumped.tracker_pos());
     /// ```
-    pub fn skip_num_chars(&mut self, num: usize) {
-        for _ in 0..num {
-            self.advance_char();
-            if self.is_at_end() {
-                break;
-            }
-        }
+    ///
+    /// [`Tracker`]: trait.Tracker.html
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    pub fn tracker_pos_at(&self, byte_pos: usize) -> T::Pos where T: Clone {
+        let mut clone = self.clone();
+        clone.jump_to(byte_pos);
+        clone.tracker_pos()
     }
-    /// Skips over `num` chars in this slicer's string, and returns the area skipped over as a string slice.
+    
+    /// Gets the scan limit set by [`set_scan_limit`].
     ///
-    /// Returns `None` if this slicer is past the end of the string.
+    /// [`set_scan_limit`]: struct.StrSlicer.html#method.set_scan_limit
+    #[inline]
+    pub fn scan_limit(&self) -> Option<usize> {
+        self.scan_limit
+    }
+    /// Sets the maximum number of bytes that a single search for a [`Pattern`] may move the slicer,
+    /// or removes the limit if `None` is given.
+    ///
+    /// This affects [`skip_until`] and all of the methods built on top of it, such as [`slice_until`]
+    /// and [`skip_until_after`], as well as [`skip_until_last`]. If the pattern isn't found within `max_bytes` of the current position,
+    /// the slicer stops at the limit (rounded down to a char boundary), and slicing methods return
+    /// what was found up to there. Only the string within the limit is searched, so a match that
+    /// starts within it but reaches past it isn't found, unless it starts right at the limit.
+    /// A limit that reaches past the end of the string has no effect,
+    /// so searches still stop at the end as usual. Jumps, such as [`jump_to`] and [`skip_to_end`],
+    /// aren't limited.
+    ///
+    /// This is useful to put a hard cap on how far untrusted input can make a parser go in one step.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "🌷 For a period in 1636-1637, tulips were considered extremely valuable.".as_slicer();
-    /// assert_eq!(slicer.slice_num_chars(1), Some("🌷"));
+    /// let mut slicer = "abcdefgh;".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// assert_eq!(slicer.slice_until(';'), Some("abcd"));
+    /// assert_eq!(slicer.slice_until(';'), Some("efgh"));
+    /// assert_eq!(slicer.slice_until(';'), Some(""));
     /// ```
-    pub fn slice_num_chars(&mut self, num: usize) -> Option<&'str str> {
-        let start_pos = self.byte_pos;
-        if start_pos >= self.end_byte_pos() {
-            None
-        } else {
-            self.skip_num_chars(num);
-            let end_pos = self.byte_pos;
-            Some(&self.string[start_pos..end_pos])
-        }
-    }
-    
-    /// Checks whether or not the given [`Pattern`] is next.
     ///
-    /// # Examples
+    /// The search itself never looks past the limit, so the slicer's tracker doesn't either:
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "123456".as_slicer();
-    /// assert_eq!(slicer.skip_over("123"), true);
-    /// assert_eq!(slicer.is_next("456"), true);
+    /// use slicer::trackers::CallbackLineTracker;
+    ///
+    /// let mut newlines = Vec::new();
+    /// {
+    ///     let tracker = CallbackLineTracker::new(|byte_pos| newlines.push(byte_pos));
+    ///     let mut slicer = "a\nb\nc\nd;".as_slicer_with_tracker(tracker);
+    ///     slicer.set_scan_limit(Some(1));
+    ///     slicer.skip_until(';');
+    ///     assert_eq!(slicer.byte_pos(), 1);
+    /// }
+    /// assert_eq!(newlines, []);
     /// ```
-    pub fn is_next<P: Pattern>(&self, mut pattern: P) -> bool {
-        pattern.is_next(self)
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    /// [`skip_until`]: struct.StrSlicer.html#method.skip_until
+    /// [`slice_until`]: struct.StrSlicer.html#method.slice_until
+    /// [`skip_until_after`]: struct.StrSlicer.html#method.skip_until_after
+    /// [`skip_until_last`]: struct.StrSlicer.html#method.skip_until_last
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    /// [`skip_to_end`]: struct.StrSlicer.html#method.skip_to_end
+    pub fn set_scan_limit(&mut self, max_bytes: Option<usize>) {
+        self.scan_limit = max_bytes;
     }
     
-    /// Checks whether or not the given [`Pattern`] is next, if its next, it skips over
-    /// the pattern and returns true, if its not it does nothing and returns false.
+    /// Points the slicer at a new string, moving it back to the start and resetting its [`Tracker`].
+    ///
+    /// This lets a slicer (and its tracker) be reused for many strings, instead of creating a
+    /// new one for each of them. The new string must live at least as long as the slicer's
+    /// `'str` lifetime, just like the string it was created with.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "123456".as_slicer();
-    /// if slicer.skip_over("123") {
-    ///     assert_eq!(slicer.is_next("456"), true);
-    /// } else {
-    ///     unreachable!()
-    /// }
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "first\nstring".as_slicer_with_tracker(LineTracker::new());
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    ///
+    /// slicer.retarget("second string");
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// assert_eq!(slicer.tracker_pos(), 0);
+    /// assert_eq!(slicer.slice_until(" "), Some("second"));
     /// ```
     ///
-    /// [`Pattern`]: trait.Pattern.html
-    pub fn skip_over<P: Pattern>(&mut self, mut pattern: P) -> bool {
-        if pattern.is_next(self) {
-            unsafe {
-                pattern.skip_over_unchecked(self);
-            }
-            true
-        } else {
-            false
-        }
+    /// [`Tracker`]: trait.Tracker.html
+    pub fn retarget(&mut self, string: &'str str) {
+        self.string = string;
+        self.byte_pos = 0;
+        self.tracker.reset();
     }
-    /// Skips over the given [`Pattern`] without checking to see if its actually next.
+    
+    //pub fn skip_num_bytes(&mut self, num: usize);
+    //pub fn slice_num_bytes(&mut self, num: usize) -> Option<&'str str>;
+    
+    /// Moves forward by `n` bytes, without ever panicking.
     ///
-    /// You should almost always prefer to use [`skip_over`].
+    /// Unlike [`jump_to`], the slicer stops at the end of the string if `n` goes past it, and if
+    /// it would land in the middle of a UTF-8 code point, it stops at the start of that code point instead.
     ///
-    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
-    /// [`Pattern`]: trait.Pattern.html
-    pub unsafe fn skip_over_unchecked<P: Pattern>(&mut self, mut pattern: P) {
-        pattern.skip_over_unchecked(self)
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a🌺b".as_slicer();
+    /// slicer.advance_bytes(3); //lands in the middle of the hibiscus emoji, so it rounds down
+    /// assert_eq!(slicer.byte_pos(), 1);
+    /// slicer.advance_bytes(usize::max_value());
+    /// assert_eq!(slicer.byte_pos(), 6);
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    pub fn advance_bytes(&mut self, n: usize) {
+        let mut byte_pos = self.byte_pos.saturating_add(n).min(self.end_byte_pos());
+        while !self.string.is_char_boundary(byte_pos) {
+            byte_pos -= 1;
+        }
+        unsafe {
+            self.jump_to_unchecked(byte_pos);
+        }
     }
     
-    /// Skips forward until the given [`Pattern`] is next.
+    /// Skips over `num` chars in this slicer's string.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// slicer.skip_until("sentence");
-    /// assert_eq!(slicer.is_next("sentence"), true);
+    /// let mut slicer = "🌷 Tulip!".as_slicer();
+    /// slicer.skip_num_chars(1);
+    /// assert_eq!(slicer.cut_off(), Some(" Tulip!"));
     /// ```
+    pub fn skip_num_chars(&mut self, num: usize) {
+        self.skip_num_chars_checked(num);
+    }
+    /// Skips over `num` chars in this slicer's string, and returns the number of chars that were actually skipped.
     ///
-    /// [`Pattern`]: trait.Pattern.html
-    pub fn skip_until<P: Pattern>(&mut self, mut pattern: P) {
-        pattern.skip_until(self);
+    /// This is less than `num` if the end of the string was hit first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.skip_num_chars_checked(5), 3);
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    pub fn skip_num_chars_checked(&mut self, num: usize) -> usize {
+        let mut skipped = 0;
+        while skipped < num && !self.is_at_end() {
+            self.advance_char();
+            skipped += 1;
+        }
+        skipped
     }
-    /// Skips forward until the given [`Pattern`] is next, and returns the area skipped over as a string slice.
+    /// Skips over `num` chars in this slicer's string, and returns the area skipped over as a string slice.
     ///
     /// Returns `None` if this slicer is past the end of the string.
     ///
@@ -563,305 +1729,3470 @@ impl<'str, T: Tracker> StrSlicer<'str, T> {
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// assert_eq!(slicer.slice_until("sentence"), Some("This is a "));
+    /// let mut slicer = "🌷 For a period in 1636-1637, tulips were considered extremely valuable.".as_slicer();
+    /// assert_eq!(slicer.slice_num_chars(1), Some("🌷"));
     /// ```
-    ///
-    /// [`Pattern`]: trait.Pattern.html
-    pub fn slice_until<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+    pub fn slice_num_chars(&mut self, num: usize) -> Option<&'str str> {
         let start_pos = self.byte_pos;
         if start_pos >= self.end_byte_pos() {
             None
         } else {
-            self.skip_until(pattern);
+            self.skip_num_chars(num);
             let end_pos = self.byte_pos;
             Some(&self.string[start_pos..end_pos])
         }
     }
-    
-    /// Skips forward until the given [`Pattern`] is next, then skips over the pattern.
+    /// Same as [`slice_num_chars`], except that it fails if fewer than `num` chars are left.
+    ///
+    /// If there are enough chars, they're skipped over and returned as a string slice. If there aren't,
+    /// the slicer doesn't move and the number of chars that are left is returned as the error.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// slicer.skip_until_after("sentence");
-    /// assert_eq!(slicer.is_next("."), true);
+    /// let mut slicer = "ab".as_slicer();
+    /// assert_eq!(slicer.try_slice_num_chars(5), Err(2));
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// assert_eq!(slicer.try_slice_num_chars(2), Ok("ab"));
     /// ```
     ///
-    /// [`Pattern`]: trait.Pattern.html
-    pub fn skip_until_after<P: Pattern>(&mut self, mut pattern: P) {
-        pattern.skip_until(self);
-        if !self.is_at_end() {
-            //`skip_until` skips through the string until the pattern is found, so we're safe to
-            //assume the pattern is next and we don't need to use the checked version of `skip_over`
-            unsafe {
-                pattern.skip_over_unchecked(self);
-            }
+    /// [`slice_num_chars`]: struct.StrSlicer.html#method.slice_num_chars
+    pub fn try_slice_num_chars(&mut self, num: usize) -> Result<&'str str, usize> {
+        let mut probe = self.probe();
+        let available = probe.skip_num_chars_checked(num);
+        if available < num {
+            return Err(available);
+        }
+        let start_pos = self.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(probe.byte_pos);
         }
+        Ok(&self.string[start_pos..self.byte_pos])
     }
-    /// Skips forward until the given [`Pattern`] is next, then skips over the pattern and returns the area skipped over as a string slice.
+    /// Slices successive fields that are `widths[i]` chars long out of the current line,
+    /// as used by fixed-width record formats.
     ///
-    /// Returns `None` if this slicer is past the end of the string.
+    /// Nothing is trimmed off the fields. If the line runs out, the last field is cut short and
+    /// the fields after it are left out, so the returned `Vec` can be shorter than `widths`.
+    /// Fields never reach past the end of the line, and the slicer is left before the line
+    /// terminator (`\n` or `\r\n`), if there is one.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// assert_eq!(slicer.slice_until("sentence"), Some("This is a "));
+    /// let mut slicer = "JohnNYC10001".as_slicer();
+    /// assert_eq!(slicer.slice_columns(&[4, 3, 5]), ["John", "NYC", "10001"]);
+    ///
+    /// let mut slicer = "Ann LA".as_slicer();
+    /// assert_eq!(slicer.slice_columns(&[4, 3, 5]), ["Ann ", "LA"]);
+    ///
+    /// let mut slicer = "ab\r\ncdefg".as_slicer();
+    /// assert_eq!(slicer.slice_columns(&[4, 3]), ["ab"]);
+    /// slicer.skip_over("\r\n");
+    /// assert_eq!(slicer.slice_columns(&[4, 3]), ["cdef", "g"]);
     /// ```
+    pub fn slice_columns(&mut self, widths: &[usize]) -> Vec<&'str str> {
+        let cut_off = match self.cut_off() {
+            None => return Vec::new(),
+            Some(cut_off) => cut_off
+        };
+        let line_len = match cut_off.find('\n') {
+            None => cut_off.len(),
+            Some(newline_pos) if cut_off[..newline_pos].ends_with('\r') => newline_pos - 1,
+            Some(newline_pos) => newline_pos
+        };
+        //slice the fields out of an untracked slicer over just this line, so they can't run into the next one
+        let mut line = StrSlicer::new(&self.string[..self.byte_pos + line_len]);
+        line.byte_pos = self.byte_pos;
+        let fields = widths.iter()
+            .map_while(|&width| line.slice_num_chars(width))
+            .collect();
+        unsafe {
+            self.jump_to_unchecked(line.byte_pos);
+        }
+        fields
+    }
+    /// Skips over exactly `byte_len` bytes, and returns the area skipped over as a string slice.
     ///
-    /// [`Pattern`]: trait.Pattern.html
-    pub fn slice_until_after<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+    /// Returns `None` and doesn't move if less than `byte_len` bytes remain, or if skipping
+    /// them would end in the middle of a UTF-8 code point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc🌺".as_slicer();
+    /// assert_eq!(slicer.slice_fixed(3), Some("abc"));
+    /// assert_eq!(slicer.slice_fixed(2), None); //the hibiscus emoji is 4 bytes long
+    /// assert_eq!(slicer.slice_fixed(5), None);
+    /// assert_eq!(slicer.slice_fixed(4), Some("🌺"));
+    /// ```
+    pub fn slice_fixed(&mut self, byte_len: usize) -> Option<&'str str> {
         let start_pos = self.byte_pos;
-        if start_pos >= self.end_byte_pos() {
+        let end_pos = start_pos.checked_add(byte_len)?;
+        if end_pos > self.end_byte_pos() || !self.string.is_char_boundary(end_pos) {
             None
         } else {
-            self.skip_until_after(pattern);
-            let end_pos = self.byte_pos;
+            unsafe {
+                self.jump_to_unchecked(end_pos);
+            }
             Some(&self.string[start_pos..end_pos])
         }
     }
-    
-    /// Skips forward until a non-whitespace character is next.
-    ///
-    /// If a non-whitespace character is already next, nothing is done.
-    ///
-    /// Equivalent to `skip_until(|char: char| !char.is_whitespace())`
+    /// Skips over the next char and returns it, or returns an [`EndOfInput`] error without moving
+    /// if this slicer is at the end of the string.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a flower 🌹.".as_slicer();
-    /// slicer.skip_over("This");
-    /// slicer.skip_whitespace();
-    /// assert_eq!(slicer.is_next("is"), true);
-    /// ```
-    pub fn skip_whitespace(&mut self) {
-        self.skip_until(|char: char| !char.is_whitespace());
-    }
-    /// Skips forward until a non-whitespace character is next, and returns the area skipped over as a string slice.
+    /// use slicer::EndOfInput;
     ///
-    /// If a non-whitespace character is already next, nothing is done.
+    /// let mut slicer = "a🌺".as_slicer();
+    /// assert_eq!(slicer.expect_char(), Ok('a'));
+    /// assert_eq!(slicer.expect_char(), Ok('🌺'));
+    /// assert_eq!(slicer.expect_char(), Err(EndOfInput { byte_pos: 5 }));
+    /// ```
     ///
-    /// Returns `None` if this slicer is past the end of the string.
+    /// [`EndOfInput`]: struct.EndOfInput.html
+    pub fn expect_char(&mut self) -> Result<char, EndOfInput> {
+        match self.cut_off().and_then(|cut_off| cut_off.chars().next()) {
+            Some(char) => {
+                let byte_pos = self.byte_pos;
+                unsafe {
+                    self.jump_to_unchecked(byte_pos + char.len_utf8());
+                }
+                Ok(char)
+            },
+            None => Err(EndOfInput {
+                byte_pos: self.byte_pos
+            })
+        }
+    }
+    /// Skips over a single escape sequence starting with the `escape` char, and returns the raw
+    /// escape sequence as a string slice along with the char it stands for.
     ///
-    /// Equivalent to `slice_until(|char: char| !char.is_whitespace())`
+    /// The recognized escape sequences (shown here with `\` as the escape char) are `\n`, `\t`, `\r`,
+    /// `\0`, `\\` (the escape char itself) and `\u{…}` with 1 to 6 hex digits. Returns `None`
+    /// and doesn't move if the escape char isn't next, or if it isn't followed by one of these.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a flower 🌹.".as_slicer();
-    /// slicer.skip_over("This");
-    /// assert_eq!(slicer.slice_whitespace(), Some(" "));
+    /// let mut slicer = r"\n\u{41}\q".as_slicer();
+    /// assert_eq!(slicer.slice_escape('\\'), Some((r"\n", '\n')));
+    /// assert_eq!(slicer.slice_escape('\\'), Some((r"\u{41}", 'A')));
+    /// assert_eq!(slicer.slice_escape('\\'), None);
+    /// assert_eq!(slicer.cut_off(), Some(r"\q"));
     /// ```
-    pub fn slice_whitespace(&mut self) -> Option<&'str str> {
-        self.slice_until(|char: char| !char.is_whitespace())
+    pub fn slice_escape(&mut self, escape: char) -> Option<(&'str str, char)> {
+        let start_pos = self.byte_pos;
+        let mut probe = self.probe();
+        if !probe.skip_over(escape) {
+            return None;
+        }
+        let code = probe.slice_num_chars(1)?;
+        let decoded = match code {
+            _ if code.starts_with(escape) => escape,
+            "n" => '\n',
+            "t" => '\t',
+            "r" => '\r',
+            "0" => '\0',
+            "u" => {
+                if !probe.skip_over('{') {
+                    return None;
+                }
+                let hex = probe.slice_until('}')?;
+                if !probe.skip_over('}') || hex.is_empty() || hex.len() > 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                std::char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+            },
+            _ => return None
+        };
+        let end_pos = probe.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(end_pos);
+        }
+        Some((&self.string[start_pos..end_pos], decoded))
     }
     
-    /// Skips forward until a whitespace character is next.
-    ///
-    /// If a whitespace character is already next, nothing is done.
-    ///
-    /// Opposite of [`skip_whitespace`].
-    ///
-    /// Equivalent to `skip_until(|char: char| char.is_whitespace())`
+    /// Checks whether or not the given [`Pattern`] is next.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// slicer.skip_non_whitespace();
-    /// assert_eq!(slicer.is_next(" is"), true);
+    /// let mut slicer = "123456".as_slicer();
+    /// assert_eq!(slicer.skip_over("123"), true);
+    /// assert_eq!(slicer.is_next("456"), true);
+    /// assert_eq!(slicer.is_next('4'), true);
+    /// assert_eq!(slicer.is_next('1'), false);
+    /// assert_eq!(slicer.is_next(|c: char| c == '4'), true);
     /// ```
-    ///
-    /// [`skip_whitespace`]: struct.StrSlicer.html#method.skip_whitespace
-    pub fn skip_non_whitespace(&mut self) {
-        self.skip_until(|char: char| char.is_whitespace());
+    pub fn is_next<P: Pattern>(&self, mut pattern: P) -> bool {
+        pattern.is_next(self)
     }
-    /// Skips forward until a whitespace character is next, and returns the area skipped over as a string slice.
-    ///
-    /// If a whitespace character is already next, nothing is done.
-    ///
-    /// Returns `None` if this slicer is past the end of the string.
-    ///
-    /// Opposite of [`slice_whitespace`].
+    /// Checks whether or not the given [`Pattern`] occurs anywhere at or after the current position, without moving.
     ///
-    /// Equivalent to `slice_until(|char: char| char.is_whitespace())`
+    /// For string slices this is the same as [`str::contains`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.contains)
+    /// on the rest of the string, and for char predicates it checks whether any of the remaining chars match.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a sentence.".as_slicer();
-    /// assert_eq!(slicer.slice_non_whitespace(), Some("This"));
+    /// let slicer = "hello".as_slicer();
+    /// assert_eq!(slicer.contains('l'), true);
+    /// assert_eq!(slicer.contains('z'), false);
+    /// assert_eq!(slicer.byte_pos(), 0);
     /// ```
     ///
-    /// [`slice_whitespace`]: struct.StrSlicer.html#method.slice_whitespace
-    pub fn slice_non_whitespace(&mut self) -> Option<&'str str> {
-        self.slice_until(|char: char| char.is_whitespace())
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn contains<P: Pattern>(&self, mut pattern: P) -> bool {
+        self.probe().skip_until_found(&mut pattern)
     }
-    
-    /// Skips past the rest of the line.
+    /// Checks whether or not the given [`Pattern`] is next at the given byte index, without moving.
     ///
-    /// Equivalent to `skip_until_after('\n')`
+    /// Returns `false` if `byte_pos` is out of bounds or not on a UTF-8 code point boundary.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer();
-    /// slicer.skip_line();
-    /// slicer.skip_line();
-    /// assert_eq!(slicer.is_next("Line 3"), true);
+    /// let slicer = "let x = 5;".as_slicer();
+    /// assert_eq!(slicer.is_next_at(6, '='), true);
+    /// assert_eq!(slicer.is_next_at(4, "x ="), true);
+    /// assert_eq!(slicer.is_next_at(100, ';'), false);
+    /// assert_eq!(slicer.byte_pos(), 0);
     /// ```
-    pub fn skip_line(&mut self) {
-        self.skip_until_after('\n');
-    }
-    /// Skips past the rest of the line, and returns the area skipped over as a string slice.
     ///
-    /// The returned string slice also has the newline characters removed, regardless of
-    /// whether the line ending is `\r\n` or `\n`. It handles line endings the same way as
-    /// the standard library function [`str::lines()`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.lines).
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn is_next_at<P: Pattern>(&self, byte_pos: usize, mut pattern: P) -> bool {
+        if byte_pos > self.end_byte_pos() || !self.string.is_char_boundary(byte_pos) {
+            return false;
+        }
+        let mut probe = self.probe();
+        probe.byte_pos = byte_pos;
+        pattern.is_next(&probe)
+    }
+    
+    /// Checks whether or not the given [`Pattern`] is next, if its next, it skips over
+    /// the pattern and returns true, if its not it does nothing and returns false.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer();
-    /// assert_eq!(slicer.slice_line(), Some("Line 1"));
-    /// assert_eq!(slicer.slice_line(), Some("Line 2"));
-    /// assert_eq!(slicer.slice_line(), Some("Line 3"));
+    /// let mut slicer = "123456".as_slicer();
+    /// if slicer.skip_over("123") {
+    ///     assert_eq!(slicer.is_next("456"), true);
+    /// } else {
+    ///     unreachable!()
+    /// }
     /// ```
-    pub fn slice_line(&mut self) -> Option<&'str str> {
-        let line = self.slice_until_after('\n');
-        line.map(|line| {
-            line.trim_right_matches(|char: char| char == '\n' || char == '\r')
-        })
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn skip_over<P: Pattern>(&mut self, mut pattern: P) -> bool {
+        match pattern.match_len(self) {
+            Some(len) => {
+                let byte_pos = self.byte_pos;
+                unsafe {
+                    self.jump_to_unchecked(byte_pos + len);
+                }
+                true
+            },
+            None => false
+        }
     }
-
-    /// Skips to the end of the string.
+    /// Same as [`skip_over`], except that it returns the number of bytes skipped over if the pattern was next.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
-    /// slicer.skip_to_end();
-    /// assert_eq!(slicer.is_at_end(), true);
+    /// let mut slicer = "🌺🌷 flowers".as_slicer();
+    /// assert_eq!(slicer.skip_over_len("🌺🌷"), Some(8));
+    /// assert_eq!(slicer.skip_over_len("🌺"), None);
+    /// assert_eq!(slicer.byte_pos(), 8);
     /// ```
-    pub fn skip_to_end(&mut self) {
-        unsafe {
-            let byte_pos = self.end_byte_pos();
-            self.jump_to_unchecked(byte_pos);
+    ///
+    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
+    pub fn skip_over_len<P: Pattern>(&mut self, pattern: P) -> Option<usize> {
+        let start_pos = self.byte_pos;
+        if self.skip_over(pattern) {
+            Some(self.byte_pos - start_pos)
+        } else {
+            None
         }
     }
-    /// Skips to the end of the string, and returns the area skipped over as a string slice.
+    /// Same as [`skip_over`], except that it returns the tracker's position from before and after
+    /// skipping over the pattern if it was next.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
-    /// slicer.skip_until_after("skip over");
-    /// assert_eq!(slicer.slice_to_end(), Some(" entirely."));
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "/* multi\nline */ code".as_slicer_with_tracker(LineTracker::new());
+    /// let (before, after) = slicer.skip_over_spanned("/* multi\nline */").unwrap();
+    /// assert!(after > before);
+    /// assert_eq!((before, after), (0, 1));
+    /// assert_eq!(slicer.skip_over_spanned("/*"), None);
     /// ```
-    pub fn slice_to_end(&mut self) -> Option<&'str str> {
+    ///
+    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
+    pub fn skip_over_spanned<P: Pattern>(&mut self, pattern: P) -> Option<(T::Pos, T::Pos)> {
+        let before = self.tracker_pos();
+        if self.skip_over(pattern) {
+            Some((before, self.tracker_pos()))
+        } else {
+            None
+        }
+    }
+    /// Same as [`skip_over`], except that it returns the area skipped over as a string slice if the pattern was next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Bytes;
+    ///
+    /// let mut slicer = "2024-01-01".as_slicer();
+    /// assert_eq!(slicer.slice_over(Bytes(4)), Some("2024"));
+    /// assert_eq!(slicer.slice_over('-'), Some("-"));
+    /// assert_eq!(slicer.slice_over('-'), None);
+    /// ```
+    ///
+    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
+    pub fn slice_over<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+        let start_pos = self.byte_pos;
+        if self.skip_over(pattern) {
+            Some(&self.string[start_pos..self.byte_pos])
+        } else {
+            None
+        }
+    }
+    /// Repeatedly skips over the given [`Pattern`] for as long as it is next, and returns the number of times it was skipped over.
+    ///
+    /// Stops after skipping over a match that didn't move the slicer, so zero-width patterns can't cause an infinite loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "ababa!".as_slicer();
+    /// assert_eq!(slicer.skip_over_all("ab"), 2);
+    /// assert_eq!(slicer.cut_off(), Some("a!"));
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn skip_over_all<P: Pattern>(&mut self, mut pattern: P) -> usize {
+        let mut count = 0;
+        while pattern.is_next(self) {
+            let byte_pos = self.byte_pos;
+            unsafe {
+                pattern.skip_over_unchecked(self);
+            }
+            count += 1;
+            if self.byte_pos == byte_pos {
+                break;
+            }
+        }
+        count
+    }
+    /// Skips over the run of `c` chars that is next, and returns the length of the run in chars.
+    ///
+    /// Returns 0 and doesn't move if `c` isn't next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "===title".as_slicer();
+    /// assert_eq!(slicer.skip_run('='), 3);
+    /// assert_eq!(slicer.cut_off(), Some("title"));
+    /// assert_eq!(slicer.skip_run('='), 0);
+    ///
+    /// let mut slicer = "aaaaaaaa;".as_slicer();
+    /// slicer.set_scan_limit(Some(2));
+    /// assert_eq!(slicer.skip_run('a'), 2);
+    /// ```
+    pub fn skip_run(&mut self, c: char) -> usize {
+        if self.is_at_end() {
+            return 0;
+        }
+        //only count the chars within the scan limit
+        let run = self.string[self.byte_pos..self.limit_byte_pos()].chars().take_while(|&char| char == c).count();
+        let byte_pos = self.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(byte_pos + run * c.len_utf8());
+        }
+        run
+    }
+    /// Skips over the given [`Pattern`] if it's next, and returns whether or not it was.
+    ///
+    /// The same as [`skip_over`], but named for grammar code where a token is allowed to be left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a, b c".as_slicer();
+    /// let mut items = Vec::new();
+    /// while let Some(item) = slicer.slice_until(|char: char| char == ',' || char == ' ') {
+    ///     items.push(item);
+    ///     slicer.optional(',');
+    ///     slicer.optional_whitespace();
+    /// }
+    /// assert_eq!(items, ["a", "b", "c"]);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
+    #[inline]
+    pub fn optional<P: Pattern>(&mut self, pattern: P) -> bool {
+        self.skip_over(pattern)
+    }
+    /// Skips over any whitespace that is next.
+    ///
+    /// The same as [`skip_whitespace`], but named for grammar code where whitespace is allowed to be left out.
+    ///
+    /// [`skip_whitespace`]: struct.StrSlicer.html#method.skip_whitespace
+    #[inline]
+    pub fn optional_whitespace(&mut self) {
+        self.skip_whitespace();
+    }
+    /// Skips over the given [`Pattern`] without checking to see if its actually next.
+    ///
+    /// You should almost always prefer to use [`skip_over`].
+    ///
+    /// # Safety
+    ///
+    /// The pattern must actually be next in the slicer, otherwise the slicer may be
+    /// left on a position that is not a UTF-8 code point boundary. See [`jump_to_unchecked`].
+    ///
+    /// In debug builds, skipping over a string slice pattern that would leave the slicer
+    /// past the end of the string or inside a char panics.
+    ///
+    /// [`jump_to_unchecked`]: struct.StrSlicer.html#method.jump_to_unchecked
+    /// [`skip_over`]: struct.StrSlicer.html#method.skip_over
+    /// [`Pattern`]: trait.Pattern.html
+    pub unsafe fn skip_over_unchecked<P: Pattern>(&mut self, mut pattern: P) {
+        pattern.skip_over_unchecked(self)
+    }
+    
+    /// Skips forward until the given [`Pattern`] is next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// slicer.skip_until("sentence");
+    /// assert_eq!(slicer.is_next("sentence"), true);
+    /// ```
+    ///
+    /// If the pattern is already next, the slicer doesn't move:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a;b".as_slicer();
+    /// slicer.skip_until("a;");
+    /// slicer.skip_until('a');
+    /// assert_eq!(slicer.byte_pos(), 0);
+    ///
+    /// let mut calls = 0;
+    /// slicer.skip_until(|char: char| { calls += 1; char == 'a' });
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// assert_eq!(calls, 1);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn skip_until<P: Pattern>(&mut self, mut pattern: P) {
+        self.scan_until(&mut pattern);
+    }
+    /// Skips forward until the given [`Pattern`] is next, and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// assert_eq!(slicer.slice_until("sentence"), Some("This is a "));
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn slice_until<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
         let start_pos = self.byte_pos;
         if start_pos >= self.end_byte_pos() {
             None
         } else {
-            self.skip_to_end();
+            self.skip_until(pattern);
             let end_pos = self.byte_pos;
             Some(&self.string[start_pos..end_pos])
         }
     }
-    /// Checks whether or not the string slicer is at or past the end of the string it is operating on.
+    /// Skips forward until the given [`Pattern`] is next, and returns the area skipped over as a string slice.
+    ///
+    /// The same as [`slice_until`], except that an empty string slice is returned instead of
+    /// `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "key;".as_slicer();
+    /// assert_eq!(slicer.slice_rest_until(';'), "key");
+    /// slicer.skip_over(';');
+    /// assert_eq!(slicer.slice_rest_until(';'), "");
+    /// ```
+    ///
+    /// [`slice_until`]: struct.StrSlicer.html#method.slice_until
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn slice_rest_until<P: Pattern>(&mut self, pattern: P) -> &'str str {
+        self.slice_until(pattern).unwrap_or("")
+    }
+    /// Same as [`slice_until`], except that the slice is paired with the tracker's position
+    /// from before it was sliced, which is where the slice starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "first\nsecond".as_slicer_with_tracker(LineTracker::new());
+    /// assert_eq!(slicer.slice_until_with_pos('\n'), Some(("first", 0)));
+    /// slicer.skip_over('\n');
+    /// assert_eq!(slicer.slice_until_with_pos('\n'), Some(("second", 1)));
+    /// assert_eq!(slicer.slice_until_with_pos('\n'), None);
+    /// ```
+    ///
+    /// [`slice_until`]: struct.StrSlicer.html#method.slice_until
+    pub fn slice_until_with_pos<P: Pattern>(&mut self, pattern: P) -> Option<(&'str str, T::Pos)> {
+        let pos = self.tracker_pos();
+        self.slice_until(pattern).map(|slice| (slice, pos))
+    }
+    /// Same as [`slice_until`], except that the slice is paired with whether or not the pattern
+    /// was actually found, as opposed to the slicer stopping at the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "key;abc".as_slicer();
+    /// assert_eq!(slicer.slice_until_checked(';'), (Some("key"), true));
+    /// slicer.skip_over(';');
+    /// assert_eq!(slicer.slice_until_checked(';'), (Some("abc"), false));
+    /// assert_eq!(slicer.slice_until_checked(';'), (None, false));
+    ///
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.slice_until_checked(';'), (Some("abc"), false));
+    /// ```
+    ///
+    /// [`slice_until`]: struct.StrSlicer.html#method.slice_until
+    pub fn slice_until_checked<P: Pattern>(&mut self, mut pattern: P) -> (Option<&'str str>, bool) {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            (None, false)
+        } else {
+            self.scan_until(&mut pattern);
+            let found = pattern.is_next(self);
+            (Some(&self.string[start_pos..self.byte_pos]), found)
+        }
+    }
+    /// Skips forward to where `f` says to stop, and returns the area skipped over as a string slice.
+    ///
+    /// `f` is called once with the rest of the string, and returns the byte offset to stop
+    /// at relative to the slicer's position, or `None` to skip to the end.
+    /// Returns `None` without calling `f` if this slicer is past the end of the string.
+    ///
+    /// With a [scan limit], `f` is only given the part of the rest of the string within the limit,
+    /// and `None` skips to the limit instead of the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the returned offset is past the end of the string slice given to `f`,
+    /// or not on a UTF-8 code point boundary, the same way [`jump_to`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a -> b -> c".as_slicer();
+    /// assert_eq!(slicer.slice_until_fn(|rest| rest.find("->")), Some("a "));
+    /// assert_eq!(slicer.cut_off(), Some("-> b -> c"));
+    ///
+    /// let mut slicer = "abcdefgh;x".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// assert_eq!(slicer.slice_until_fn(|rest| rest.find(';')), Some("abcd"));
+    /// ```
+    ///
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    /// [scan limit]: struct.StrSlicer.html#method.set_scan_limit
+    pub fn slice_until_fn<F: FnMut(&'str str) -> Option<usize>>(&mut self, mut f: F) -> Option<&'str str> {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            return None;
+        }
+        let limit_pos = self.limit_byte_pos();
+        let limited = &self.string[start_pos..limit_pos];
+        match f(limited) {
+            None => unsafe {
+                self.jump_to_unchecked(limit_pos);
+            },
+            Some(offset) if offset > limited.len() => jump_oob_fail(limited, offset),
+            Some(offset) => self.jump_to(start_pos + offset)
+        }
+        let end_pos = self.byte_pos;
+        Some(&self.string[start_pos..end_pos])
+    }
+    /// Skips forward until either the given [`Pattern`] is next or `max_chars` chars were skipped,
+    /// whichever comes first, and returns the area skipped over as a string slice.
+    ///
+    /// An empty string slice is returned if this slicer is past the end of the string. To find
+    /// out which of the two conditions stopped the slicer, check [`is_next`] afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abcdef;".as_slicer();
+    /// assert_eq!(slicer.slice_until_or_chars(';', 3), "abc");
+    /// assert_eq!(slicer.is_next(';'), false);
+    /// assert_eq!(slicer.slice_until_or_chars(';', 10), "def");
+    /// assert_eq!(slicer.is_next(';'), true);
+    /// ```
+    ///
+    /// Like [`slice_until`], this won't go further than the [scan limit] allows:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abcdefgh;x".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// assert_eq!(slicer.slice_until_or_chars(';', 100), "abcd");
+    /// ```
+    ///
+    /// [`slice_until`]: struct.StrSlicer.html#method.slice_until
+    /// [scan limit]: struct.StrSlicer.html#method.set_scan_limit
+    ///
+    /// [`is_next`]: struct.StrSlicer.html#method.is_next
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn slice_until_or_chars<P: Pattern>(&mut self, mut pattern: P, max_chars: usize) -> &'str str {
+        let start_pos = self.byte_pos;
+        let limit_pos = self.limit_byte_pos();
+        //walk an untracked copy, so the tracker is only updated once
+        let mut probe = self.probe();
+        for _ in 0..max_chars {
+            if probe.byte_pos >= limit_pos || pattern.is_next(&probe) {
+                break;
+            }
+            probe.advance_char();
+        }
+        let end_pos = probe.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(end_pos);
+        }
+        &self.string[start_pos..end_pos]
+    }
+    
+    /// Skips forward until the given [`Pattern`] is next, then skips over the pattern.
+    ///
+    /// If the pattern isn't found, the slicer ends up at the end of the string. A pattern that
+    /// reaches right up to the end of the string is still skipped over, which also leaves the
+    /// slicer at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// slicer.skip_until_after("sentence");
+    /// assert_eq!(slicer.is_next("."), true);
+    ///
+    /// let mut slicer = "ab;".as_slicer();
+    /// slicer.skip_until_after(";");
+    /// assert_eq!(slicer.byte_pos(), 3);
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn skip_until_after<P: Pattern>(&mut self, mut pattern: P) {
+        let limited = self.scan_until(&mut pattern);
+        if !self.is_at_end() && !limited {
+            //`skip_until` skips through the string until the pattern is found, so we're safe to
+            //assume the pattern is next and we don't need to use the checked version of `skip_over`
+            unsafe {
+                pattern.skip_over_unchecked(self);
+            }
+        }
+    }
+    /// Skips forward until the given [`Pattern`] is next, then skips over the pattern and returns `true`,
+    /// but only if the pattern is actually found. If it isn't, the slicer doesn't move and `false` is returned.
+    ///
+    /// Unlike [`skip_until_after`], which skips to the end of the string when the pattern isn't
+    /// found, this is suitable for optional tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a;b".as_slicer();
+    /// assert_eq!(slicer.try_skip_until_after(';'), true);
+    /// assert_eq!(slicer.cut_off(), Some("b"));
+    ///
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.try_skip_until_after(';'), false);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// ```
+    ///
+    /// A failed search doesn't move the slicer at all, so its tracker doesn't see any movement:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::CallbackLineTracker;
+    ///
+    /// let mut newlines = Vec::new();
+    /// {
+    ///     let tracker = CallbackLineTracker::new(|byte_pos| newlines.push(byte_pos));
+    ///     let mut slicer = "a\nb\nc".as_slicer_with_tracker(tracker);
+    ///     assert_eq!(slicer.try_skip_until_after(';'), false);
+    /// }
+    /// assert_eq!(newlines, []);
+    /// ```
+    ///
+    /// [`skip_until_after`]: struct.StrSlicer.html#method.skip_until_after
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn try_skip_until_after<P: Pattern>(&mut self, mut pattern: P) -> bool {
+        if self.skip_until_found(&mut pattern) {
+            unsafe {
+                pattern.skip_over_unchecked(self);
+            }
+            true
+        } else {
+            false
+        }
+    }
+    /// Skips forward until after the first match found by `f`, or to the end of the string if there isn't one.
+    ///
+    /// `f` is called with the rest of the string at each char, moving forward one char at a time,
+    /// and returns the byte length of a match starting there, or `None` if there isn't one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of a match is out of bounds or not on a UTF-8 code point boundary,
+    /// the same way [`jump_to`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// fn dash_rule(rest: &str) -> Option<usize> {
+    ///     let dashes = rest.bytes().take_while(|&b| b == b'-').count();
+    ///     if dashes >= 3 { Some(dashes) } else { None }
+    /// }
+    ///
+    /// let mut slicer = "a -- b\n-----\nc".as_slicer();
+    /// slicer.skip_until_after_fn(dash_rule);
+    /// assert_eq!(slicer.cut_off(), Some("\nc"));
+    /// ```
+    ///
+    /// With a [scan limit], `f` is only called at chars up to the limit, and the slicer
+    /// stops at the limit if there's no match before it:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abcdefgh;x".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// slicer.skip_until_after_fn(|rest| if rest.starts_with(';') { Some(1) } else { None });
+    /// assert_eq!(slicer.byte_pos(), 4);
+    /// ```
+    ///
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    /// [scan limit]: struct.StrSlicer.html#method.set_scan_limit
+    pub fn skip_until_after_fn<F: FnMut(&'str str) -> Option<usize>>(&mut self, mut f: F) {
+        let limit_pos = self.limit_byte_pos();
+        let mut probe = self.probe();
+        //a match that starts right at the limit is still within it
+        while probe.byte_pos <= limit_pos {
+            let cut_off = match probe.cut_off() {
+                None => break,
+                Some(cut_off) => cut_off
+            };
+            if let Some(len) = f(cut_off) {
+                self.jump_to(probe.byte_pos.saturating_add(len));
+                return;
+            }
+            probe.advance_char();
+        }
+        unsafe {
+            self.jump_to_unchecked(limit_pos);
+        }
+    }
+    /// Skips forward until the given [`Pattern`] is next, then skips over the pattern and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// assert_eq!(slicer.slice_until("sentence"), Some("This is a "));
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn slice_until_after<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            None
+        } else {
+            self.skip_until_after(pattern);
+            let end_pos = self.byte_pos;
+            Some(&self.string[start_pos..end_pos])
+        }
+    }
+    /// Splits the rest of the string at the first occurrence of the given [`Pattern`], returning
+    /// the area before the pattern and the area after it, and skipping to the end of the string.
+    ///
+    /// Returns `None` and doesn't move if the pattern isn't found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a: b: c".as_slicer();
+    /// assert_eq!(slicer.slice_once(": "), Some(("a", "b: c")));
+    /// assert_eq!(slicer.is_at_end(), true);
+    ///
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.slice_once(": "), None);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn slice_once<P: Pattern>(&mut self, mut pattern: P) -> Option<(&'str str, &'str str)> {
+        let start_pos = self.byte_pos;
+        if !self.skip_until_found(&mut pattern) {
+            return None;
+        }
+        let before = &self.string[start_pos..self.byte_pos];
+        unsafe {
+            pattern.skip_over_unchecked(self);
+        }
+        let after = self.slice_to_end().unwrap_or("");
+        Some((before, after))
+    }
+    /// Skips forward until after the `close` char, and returns the area before it as a string slice.
+    ///
+    /// Meant to be used right after skipping over an opening delimiter, like a `(`.
+    /// Nesting isn't taken into account, so the first `close` char is the one that is used.
+    /// Returns `None` and doesn't move if `close` isn't found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "(abc)def".as_slicer();
+    /// slicer.skip_over('(');
+    /// assert_eq!(slicer.slice_until_close(')'), Some("abc"));
+    /// assert_eq!(slicer.cut_off(), Some("def"));
+    /// assert_eq!(slicer.slice_until_close(')'), None);
+    /// assert_eq!(slicer.cut_off(), Some("def"));
+    /// ```
+    pub fn slice_until_close(&mut self, mut close: char) -> Option<&'str str> {
+        let start_pos = self.byte_pos;
+        if !self.skip_until_found(&mut close) {
+            return None;
+        }
+        let inner = &self.string[start_pos..self.byte_pos];
+        self.skip_over(close);
+        Some(inner)
+    }
+    /// Skips forward until after the closing `quote` char, or until the end of the line if the quote
+    /// isn't closed on it, and returns the quoted content as a string slice.
+    ///
+    /// Meant to be used right after skipping over the opening quote. The line terminator is never skipped over.
+    /// See [`slice_quoted_or_eol_checked`] for finding out whether the quote was closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "\"closed\" rest\n\"unclosed\nnext".as_slicer();
+    /// slicer.skip_over('"');
+    /// assert_eq!(slicer.slice_quoted_or_eol('"'), "closed");
+    /// assert_eq!(slicer.cut_off(), Some(" rest\n\"unclosed\nnext"));
+    /// slicer.skip_line();
+    /// slicer.skip_over('"');
+    /// assert_eq!(slicer.slice_quoted_or_eol('"'), "unclosed");
+    /// assert_eq!(slicer.cut_off(), Some("\nnext"));
+    /// ```
+    ///
+    /// [`slice_quoted_or_eol_checked`]: struct.StrSlicer.html#method.slice_quoted_or_eol_checked
+    pub fn slice_quoted_or_eol(&mut self, quote: char) -> &'str str {
+        self.slice_quoted_or_eol_checked(quote).0
+    }
+    /// Same as [`slice_quoted_or_eol`], except that it also returns whether or not the closing quote was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "'closed' 'unclosed".as_slicer();
+    /// slicer.skip_over('\'');
+    /// assert_eq!(slicer.slice_quoted_or_eol_checked('\''), ("closed", true));
+    /// slicer.skip_over(" '");
+    /// assert_eq!(slicer.slice_quoted_or_eol_checked('\''), ("unclosed", false));
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`slice_quoted_or_eol`]: struct.StrSlicer.html#method.slice_quoted_or_eol
+    pub fn slice_quoted_or_eol_checked(&mut self, quote: char) -> (&'str str, bool) {
+        let content = self.slice_rest_until(|char: char| char == quote || char == '\n' || char == '\r');
+        (content, self.skip_over(quote))
+    }
+    /// Same as [`slice_until_after`], except that the area before the pattern and the pattern
+    /// itself are returned as separate string slices.
+    ///
+    /// If the pattern isn't found, the rest of the string is returned along with an empty string slice.
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let newlines = &["\r\n", "\n"][..];
+    /// let mut slicer = "line\r\nrest".as_slicer();
+    /// assert_eq!(slicer.slice_until_after_split(newlines), Some(("line", "\r\n")));
+    /// assert_eq!(slicer.slice_until_after_split(newlines), Some(("rest", "")));
+    /// assert_eq!(slicer.slice_until_after_split(newlines), None);
+    /// ```
+    ///
+    /// [`slice_until_after`]: struct.StrSlicer.html#method.slice_until_after
+    pub fn slice_until_after_split<P: Pattern>(&mut self, pattern: P) -> Option<(&'str str, &'str str)> {
+        let string = self.string;
+        self.slice_until_after_ranges(pattern).map(|(content, delimiter)| (&string[content], &string[delimiter]))
+    }
+    /// Same as [`slice_until_after_split`], except that the byte ranges of the area before the pattern
+    /// and of the pattern itself are returned, instead of string slices.
+    ///
+    /// If the pattern isn't found, the second range is empty and starts at the end of the string.
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "ab;cd".as_slicer();
+    /// assert_eq!(slicer.slice_until_after_ranges(';'), Some((0..2, 2..3)));
+    /// assert_eq!(slicer.slice_until_after_ranges(';'), Some((3..5, 5..5)));
+    /// assert_eq!(slicer.slice_until_after_ranges(';'), None);
+    /// ```
+    ///
+    /// [`slice_until_after_split`]: struct.StrSlicer.html#method.slice_until_after_split
+    pub fn slice_until_after_ranges<P: Pattern>(&mut self, mut pattern: P) -> Option<(Range<usize>, Range<usize>)> {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            None
+        } else {
+            self.scan_until(&mut pattern);
+            let delimiter_pos = self.byte_pos;
+            if pattern.is_next(self) {
+                unsafe {
+                    pattern.skip_over_unchecked(self);
+                }
+            }
+            Some((start_pos..delimiter_pos, delimiter_pos..self.byte_pos))
+        }
+    }
+    
+    /// Skips backwards through the previous occurrence of the given [`Pattern`], stopping right before it.
+    ///
+    /// Only occurrences that lie entirely before the current position are found. If there is
+    /// none, the slicer skips back to the start of the string. String slices, chars and char
+    /// predicates are searched for the same way as [`str::rfind`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.rfind).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a/b/c".as_slicer();
+    /// slicer.skip_to_end();
+    /// slicer.rskip_until_after('/');
+    /// assert_eq!(slicer.byte_pos(), 3);
+    /// ```
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn rskip_until_after<P: Pattern>(&mut self, mut pattern: P) {
+        pattern.rskip_until_after(self);
+    }
+    /// Skips forward until the last occurrence of the given pattern in the rest of the string is next,
+    /// and returns `true`, or returns `false` and doesn't move if the pattern isn't found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a.b.c".as_slicer();
+    /// assert_eq!(slicer.skip_until_last('.'), true);
+    /// assert_eq!(slicer.cut_off(), Some(".c"));
+    /// assert_eq!(slicer.skip_until_last(','), false);
+    /// assert_eq!(slicer.cut_off(), Some(".c"));
+    /// ```
+    ///
+    /// With a [scan limit], only the last occurrence within the limit is looked for:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a.b.c.d".as_slicer();
+    /// slicer.set_scan_limit(Some(3));
+    /// assert_eq!(slicer.skip_until_last('.'), true);
+    /// assert_eq!(slicer.cut_off(), Some(".c.d"));
+    ///
+    /// let mut slicer = "abcdefgh;x".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// assert_eq!(slicer.skip_until_last(';'), false);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// ```
+    ///
+    /// [scan limit]: struct.StrSlicer.html#method.set_scan_limit
+    pub fn skip_until_last<P: Pattern>(&mut self, mut pattern: P) -> bool {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            return false;
+        }
+        let limit_pos = self.limit_byte_pos();
+        let mut probe = self.probe();
+        //a match that starts right at the limit is still within it, and no later one is
+        probe.byte_pos = limit_pos;
+        if limit_pos < self.end_byte_pos() && pattern.is_next(&probe) {
+            unsafe {
+                self.jump_to_unchecked(limit_pos);
+            }
+            return true;
+        }
+        //search backwards from the limit, without going further back than the slicer's position
+        let mut rest_probe = StrSlicer::new(&self.string[start_pos..limit_pos]);
+        rest_probe.skip_to_end();
+        pattern.rskip_until_after(&mut rest_probe);
+        //check the match again with the whole string around it
+        probe.byte_pos = start_pos + rest_probe.byte_pos;
+        if pattern.is_next(&probe) {
+            let byte_pos = probe.byte_pos;
+            unsafe {
+                self.jump_to_unchecked(byte_pos);
+            }
+            true
+        } else {
+            false
+        }
+    }
+    /// Skips backwards through the previous occurrence of the given [`Pattern`], stopping right before it,
+    /// and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is at the start of the string.
+    ///
+    /// See [`rskip_until_after`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a/b/c".as_slicer();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.rslice_until_after('/'), Some("/c"));
+    /// assert_eq!(slicer.byte_pos(), 3);
+    /// assert_eq!(slicer.rslice_until_after('/'), Some("/b"));
+    /// assert_eq!(slicer.rslice_until_after('/'), Some("a"));
+    /// assert_eq!(slicer.rslice_until_after('/'), None);
+    /// ```
+    ///
+    /// [`rskip_until_after`]: struct.StrSlicer.html#method.rskip_until_after
+    /// [`Pattern`]: trait.Pattern.html
+    pub fn rslice_until_after<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+        let end_pos = self.byte_pos;
+        if end_pos == 0 {
+            None
+        } else {
+            self.rskip_until_after(pattern);
+            let start_pos = self.byte_pos;
+            Some(&self.string[start_pos..end_pos])
+        }
+    }
+    /// Jumps back to the start of the string, and returns the area jumped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is already at the start of the string. Reverse counterpart of [`slice_to_end`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "images/cat".as_slicer();
+    /// slicer.jump_to(6);
+    /// assert_eq!(slicer.rslice_to_start(), Some("images"));
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// assert_eq!(slicer.rslice_to_start(), None);
+    /// ```
+    ///
+    /// [`slice_to_end`]: struct.StrSlicer.html#method.slice_to_end
+    pub fn rslice_to_start(&mut self) -> Option<&'str str> {
+        let end_pos = self.byte_pos.min(self.end_byte_pos());
+        if end_pos == 0 {
+            None
+        } else {
+            unsafe {
+                self.jump_to_unchecked(0);
+            }
+            Some(&self.string[..end_pos])
+        }
+    }
+    
+    /// Skips forward until a non-whitespace character is next.
+    ///
+    /// If a non-whitespace character is already next, nothing is done.
+    ///
+    /// Equivalent to `skip_until(|char: char| !char.is_whitespace())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a flower 🌹.".as_slicer();
+    /// slicer.skip_over("This");
+    /// slicer.skip_whitespace();
+    /// assert_eq!(slicer.is_next("is"), true);
+    /// ```
+    pub fn skip_whitespace(&mut self) {
+        self.skip_until(|char: char| !char.is_whitespace());
+    }
+    /// Skips forward until a non-whitespace character is next, and returns the area skipped over as a string slice.
+    ///
+    /// If a non-whitespace character is already next, nothing is done.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Equivalent to `slice_until(|char: char| !char.is_whitespace())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a flower 🌹.".as_slicer();
+    /// slicer.skip_over("This");
+    /// assert_eq!(slicer.slice_whitespace(), Some(" "));
+    /// ```
+    pub fn slice_whitespace(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| !char.is_whitespace())
+    }
+    
+    /// Skips forward until a whitespace character is next.
+    ///
+    /// If a whitespace character is already next, nothing is done.
+    ///
+    /// Opposite of [`skip_whitespace`].
+    ///
+    /// Equivalent to `skip_until(|char: char| char.is_whitespace())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// slicer.skip_non_whitespace();
+    /// assert_eq!(slicer.is_next(" is"), true);
+    /// ```
+    ///
+    /// [`skip_whitespace`]: struct.StrSlicer.html#method.skip_whitespace
+    pub fn skip_non_whitespace(&mut self) {
+        self.skip_until(|char: char| char.is_whitespace());
+    }
+    /// Skips forward until a whitespace character is next, and returns the area skipped over as a string slice.
+    ///
+    /// If a whitespace character is already next, nothing is done.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Opposite of [`slice_whitespace`].
+    ///
+    /// Equivalent to `slice_until(|char: char| char.is_whitespace())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a sentence.".as_slicer();
+    /// assert_eq!(slicer.slice_non_whitespace(), Some("This"));
+    /// ```
+    ///
+    /// [`slice_whitespace`]: struct.StrSlicer.html#method.slice_whitespace
+    pub fn slice_non_whitespace(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| char.is_whitespace())
+    }
+    /// Skips over whitespace on the current line, and then slices the word that follows, up to the next whitespace character.
+    ///
+    /// Unlike [`words`], this doesn't go on to the next line: if only whitespace is left before
+    /// the end of the line or the string, the slicer stops at the line break and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "  foo bar\nbaz".as_slicer();
+    /// assert_eq!(slicer.slice_word(), Some("foo"));
+    /// assert_eq!(slicer.slice_word(), Some("bar"));
+    /// assert_eq!(slicer.slice_word(), None);
+    /// assert_eq!(slicer.is_next('\n'), true);
+    /// ```
+    ///
+    /// [`words`]: struct.StrSlicer.html#method.words
+    pub fn slice_word(&mut self) -> Option<&'str str> {
+        self.skip_until(|char: char| !char.is_whitespace() || char == '\n' || char == '\r');
+        match self.slice_non_whitespace() {
+            Some("") | None => None,
+            word => word
+        }
+    }
+    
+    /// Skips forward until a non-alphabetic character is next.
+    ///
+    /// Equivalent to `skip_until(|char: char| !char.is_alphabetic())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc123!".as_slicer();
+    /// slicer.skip_alphabetic();
+    /// assert_eq!(slicer.cut_off(), Some("123!"));
+    /// ```
+    pub fn skip_alphabetic(&mut self) {
+        self.skip_until(|char: char| !char.is_alphabetic());
+    }
+    /// Skips forward until a non-alphabetic character is next, and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Equivalent to `slice_until(|char: char| !char.is_alphabetic())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc123!".as_slicer();
+    /// assert_eq!(slicer.slice_alphabetic(), Some("abc"));
+    /// ```
+    pub fn slice_alphabetic(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| !char.is_alphabetic())
+    }
+    
+    /// Skips forward until a non-alphanumeric character is next.
+    ///
+    /// Equivalent to `skip_until(|char: char| !char.is_alphanumeric())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc123!".as_slicer();
+    /// slicer.skip_alphanumeric();
+    /// assert_eq!(slicer.cut_off(), Some("!"));
+    /// ```
+    pub fn skip_alphanumeric(&mut self) {
+        self.skip_until(|char: char| !char.is_alphanumeric());
+    }
+    /// Skips forward until a non-alphanumeric character is next, and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Equivalent to `slice_until(|char: char| !char.is_alphanumeric())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc123!".as_slicer();
+    /// assert_eq!(slicer.slice_alphanumeric(), Some("abc123"));
+    /// ```
+    pub fn slice_alphanumeric(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| !char.is_alphanumeric())
+    }
+    
+    /// Skips forward until a non-numeric character is next.
+    ///
+    /// Equivalent to `skip_until(|char: char| !char.is_numeric())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "123abc".as_slicer();
+    /// slicer.skip_numeric();
+    /// assert_eq!(slicer.cut_off(), Some("abc"));
+    /// ```
+    pub fn skip_numeric(&mut self) {
+        self.skip_until(|char: char| !char.is_numeric());
+    }
+    /// Skips forward until a non-numeric character is next, and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Equivalent to `slice_until(|char: char| !char.is_numeric())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "123abc".as_slicer();
+    /// assert_eq!(slicer.slice_numeric(), Some("123"));
+    /// ```
+    pub fn slice_numeric(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| !char.is_numeric())
+    }
+    /// Skips over the number that is next and parses it, returning the parsed value along with the byte range the number took up.
+    ///
+    /// The number that is skipped over is the longest match of:
+    ///
+    /// - an optional `+` or `-` sign,
+    /// - one or more ASCII digits,
+    /// - optionally, a `.` followed by one or more ASCII digits,
+    /// - optionally, an `e` or `E`, an optional `+` or `-` sign, and one or more ASCII digits.
+    ///
+    /// A `.` or exponent that isn't followed by digits isn't part of the number. The whole number is then parsed
+    /// with [`FromStr`], so for integer types a fraction or exponent makes parsing fail.
+    /// Returns `None` and doesn't move if there's no number next or if parsing it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "123rest".as_slicer();
+    /// assert_eq!(slicer.slice_number_spanned::<u32>(), Some((123, 0..3)));
+    /// assert_eq!(slicer.cut_off(), Some("rest"));
+    ///
+    /// let mut slicer = "x=-1.5e3.".as_slicer();
+    /// slicer.skip_over("x=");
+    /// assert_eq!(slicer.slice_number_spanned::<f64>(), Some((-1500.0, 2..8)));
+    ///
+    /// let mut slicer = "1.5".as_slicer();
+    /// assert_eq!(slicer.slice_number_spanned::<u32>(), None);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+    pub fn slice_number_spanned<N: FromStr>(&mut self) -> Option<(N, Range<usize>)> {
+        let cut_off = self.cut_off()?;
+        let bytes = cut_off.as_bytes();
+        let digits_at = |index: usize| bytes[index.min(bytes.len())..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+        let is_sign_at = |index: usize| bytes.get(index) == Some(&b'+') || bytes.get(index) == Some(&b'-');
+        
+        let mut len = if is_sign_at(0) { 1 } else { 0 };
+        let int_digits = digits_at(len);
+        if int_digits == 0 {
+            return None;
+        }
+        len += int_digits;
+        if bytes.get(len) == Some(&b'.') {
+            let fraction_digits = digits_at(len + 1);
+            if fraction_digits > 0 {
+                len += 1 + fraction_digits;
+            }
+        }
+        if bytes.get(len) == Some(&b'e') || bytes.get(len) == Some(&b'E') {
+            let exponent_start = if is_sign_at(len + 1) { len + 2 } else { len + 1 };
+            let exponent_digits = digits_at(exponent_start);
+            if exponent_digits > 0 {
+                len = exponent_start + exponent_digits;
+            }
+        }
+        
+        let value = cut_off[..len].parse().ok()?;
+        let start_pos = self.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(start_pos + len);
+        }
+        Some((value, start_pos..start_pos + len))
+    }
+    
+    /// Skips forward until a non-ASCII character is next.
+    ///
+    /// Equivalent to `skip_until(|char: char| !char.is_ascii())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc🌺".as_slicer();
+    /// slicer.skip_ascii();
+    /// assert_eq!(slicer.cut_off(), Some("🌺"));
+    /// ```
+    pub fn skip_ascii(&mut self) {
+        self.skip_until(|char: char| !char.is_ascii());
+    }
+    /// Skips forward until a non-ASCII character is next, and returns the area skipped over as a string slice.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// Equivalent to `slice_until(|char: char| !char.is_ascii())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "abc🌺".as_slicer();
+    /// assert_eq!(slicer.slice_ascii(), Some("abc"));
+    /// ```
+    pub fn slice_ascii(&mut self) -> Option<&'str str> {
+        self.slice_until(|char: char| !char.is_ascii())
+    }
+    
+    /// Skips forward while the given predicate holds, and returns the area skipped over as a string slice.
+    ///
+    /// Along with each char, the predicate is given that char's index within this call's run,
+    /// starting at 0, which makes rules like "the first char must be alphabetic" easy to write.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// fn is_ident_char(index: usize, char: char) -> bool {
+    ///     if index == 0 { char.is_alphabetic() } else { char.is_alphanumeric() }
+    /// }
+    ///
+    /// let mut slicer = "a1b2 = 3".as_slicer();
+    /// assert_eq!(slicer.slice_while_indexed(is_ident_char), Some("a1b2"));
+    ///
+    /// let mut slicer = "1ab".as_slicer();
+    /// assert_eq!(slicer.slice_while_indexed(is_ident_char), Some(""));
+    /// ```
+    pub fn slice_while_indexed<F: FnMut(usize, char) -> bool>(&mut self, mut f: F) -> Option<&'str str> {
+        let mut index = 0;
+        self.slice_until(|char: char| {
+            let matches = f(index, char);
+            index += 1;
+            !matches
+        })
+    }
+    /// Skips forward byte by byte while the given predicate holds, and returns the area skipped over as a string slice.
+    ///
+    /// This never decodes chars, which makes it a good fit for ASCII-based formats. The predicate
+    /// is only given ASCII bytes: the slicer always stops at the first non-ASCII byte, so it never
+    /// stops in the middle of a UTF-8 code point.
+    ///
+    /// Returns `None` if this slicer is past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "8080/tcp".as_slicer();
+    /// assert_eq!(slicer.slice_while_byte(|byte| byte.is_ascii_digit()), Some("8080"));
+    /// assert_eq!(slicer.cut_off(), Some("/tcp"));
+    ///
+    /// let mut slicer = "ab🌺".as_slicer();
+    /// assert_eq!(slicer.slice_while_byte(|_| true), Some("ab"));
+    ///
+    /// let mut slicer = "abcdefgh;x".as_slicer();
+    /// slicer.set_scan_limit(Some(4));
+    /// assert_eq!(slicer.slice_while_byte(|byte| byte != b';'), Some("abcd"));
+    /// ```
+    pub fn slice_while_byte<F: FnMut(u8) -> bool>(&mut self, mut f: F) -> Option<&'str str> {
+        if self.is_at_end() {
+            return None;
+        }
+        //only look at the bytes within the scan limit
+        let cut_off = &self.string[self.byte_pos..self.limit_byte_pos()];
+        let len = cut_off.bytes()
+            .position(|byte| !byte.is_ascii() || !f(byte))
+            .unwrap_or(cut_off.len());
+        let start_pos = self.byte_pos;
+        unsafe {
+            self.jump_to_unchecked(start_pos + len);
+        }
+        Some(&cut_off[..len])
+    }
+    
+    /// Skips past the rest of the line.
+    ///
+    /// Equivalent to `skip_until_after('\n')`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer();
+    /// slicer.skip_line();
+    /// slicer.skip_line();
+    /// assert_eq!(slicer.is_next("Line 3"), true);
+    /// ```
+    pub fn skip_line(&mut self) {
+        self.skip_until_after('\n');
+    }
+    /// Skips past the rest of the line, and returns the area skipped over as a string slice.
+    ///
+    /// The returned string slice also has the newline characters removed, regardless of
+    /// whether the line ending is `\r\n` or `\n`. It handles line endings the same way as
+    /// the standard library function [`str::lines()`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.lines).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer();
+    /// assert_eq!(slicer.slice_line(), Some("Line 1"));
+    /// assert_eq!(slicer.slice_line(), Some("Line 2"));
+    /// assert_eq!(slicer.slice_line(), Some("Line 3"));
+    /// ```
+    pub fn slice_line(&mut self) -> Option<&'str str> {
+        let line = self.slice_until_after('\n');
+        line.map(|line| {
+            line.trim_end_matches(&['\n', '\r'][..])
+        })
+    }
+    /// Skips over the current line, including the line terminator, and returns a new slicer over just
+    /// the line's content, see [`slice_line`].
+    ///
+    /// The returned slicer is independent from this one, so each line can be parsed on its own.
+    /// If this slicer is past the end of the string, the returned slicer operates on an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a=1\nb=2".as_slicer();
+    /// let mut pairs = Vec::new();
+    /// while !slicer.is_at_end() {
+    ///     let mut line = slicer.line_slicer();
+    ///     let key = line.slice_until('=');
+    ///     line.skip_over('=');
+    ///     pairs.push((key, line.slice_to_end()));
+    /// }
+    /// assert_eq!(pairs, [(Some("a"), Some("1")), (Some("b"), Some("2"))]);
+    /// ```
+    ///
+    /// [`slice_line`]: struct.StrSlicer.html#method.slice_line
+    pub fn line_slicer(&mut self) -> StrSlicer<'str> {
+        StrSlicer::new(self.slice_line().unwrap_or(""))
+    }
+    /// Slices the current line as a `key = value` pair split at the first `sep`, with whitespace
+    /// trimmed off of both the key and the value, as found in `.ini` and `.env` files.
+    ///
+    /// If the line contains `sep`, the whole line is skipped over, including the line terminator.
+    /// If it doesn't, or if this slicer is past the end of the string, `None` is returned and the
+    /// slicer doesn't move, so the line can be handled another way, such as an `.ini` section header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "host = localhost\n[section]\nport=8080".as_slicer();
+    /// assert_eq!(slicer.slice_key_value('='), Some(("host", "localhost")));
+    /// assert_eq!(slicer.slice_key_value('='), None);
+    /// assert_eq!(slicer.slice_line(), Some("[section]"));
+    /// assert_eq!(slicer.slice_key_value('='), Some(("port", "8080")));
+    /// assert_eq!(slicer.slice_key_value('='), None);
+    /// ```
+    pub fn slice_key_value(&mut self, sep: char) -> Option<(&'str str, &'str str)> {
+        let mut probe = self.probe();
+        let line = probe.slice_line()?;
+        let sep_pos = line.find(sep)?;
+        unsafe {
+            self.jump_to_unchecked(probe.byte_pos);
+        }
+        Some((line[..sep_pos].trim(), line[sep_pos + sep.len_utf8()..].trim()))
+    }
+    /// Skips over lines until the start of a line that is indented by at most `level` columns,
+    /// and returns `true`, or skips to the end and returns `false` if there is no such line.
+    ///
+    /// The current line is always skipped, and so are lines that only contain whitespace.
+    /// Indentation is measured in columns, with a tab moving to the next multiple of `tab_width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let outline = "a\n  a.1\n    a.1.1\n\n  a.2\nb";
+    /// let mut slicer = outline.as_slicer();
+    /// slicer.skip_line();
+    /// slicer.skip_line();
+    /// assert_eq!(slicer.skip_to_indent_at_most(2, 4), true);
+    /// assert_eq!(slicer.slice_line(), Some("  a.2"));
+    /// assert_eq!(slicer.skip_to_indent_at_most(0, 4), false);
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    pub fn skip_to_indent_at_most(&mut self, level: usize, tab_width: usize) -> bool {
+        loop {
+            self.skip_line();
+            let line = match self.cut_off() {
+                None => return false,
+                Some(cut_off) => cut_off.split('\n').next().unwrap_or(cut_off)
+            };
+            if !line.trim().is_empty() && indentation_width(line, tab_width) <= level {
+                return true;
+            }
+        }
+    }
+    /// Skips over lines that are empty or only contain whitespace, and returns the number of lines skipped over.
+    ///
+    /// The slicer is left at the start of the first line that isn't blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "\n  \nX".as_slicer();
+    /// assert_eq!(slicer.skip_blank_lines(), 2);
+    /// assert_eq!(slicer.cut_off(), Some("X"));
+    /// assert_eq!(slicer.skip_blank_lines(), 0);
+    /// ```
+    pub fn skip_blank_lines(&mut self) -> usize {
+        let mut count = 0;
+        while let Some(cut_off) = self.cut_off() {
+            let line = cut_off.split('\n').next().unwrap_or(cut_off);
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.skip_line();
+            count += 1;
+        }
+        count
+    }
+    /// Skips over a `#!` shebang line, as found at the start of script files, and returns `true`.
+    ///
+    /// Only a shebang at the very start of the string counts, so if the slicer isn't at byte index 0,
+    /// or the string doesn't start with `#!`, nothing is done and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "#!/bin/sh\ncode".as_slicer();
+    /// assert_eq!(slicer.skip_shebang(), true);
+    /// assert_eq!(slicer.cut_off(), Some("code"));
+    ///
+    /// let mut slicer = "code".as_slicer();
+    /// assert_eq!(slicer.skip_shebang(), false);
+    /// assert_eq!(slicer.byte_pos(), 0);
+    /// ```
+    pub fn skip_shebang(&mut self) -> bool {
+        if self.byte_pos == 0 && self.is_next("#!") {
+            self.skip_line();
+            true
+        } else {
+            false
+        }
+    }
+    /// Moves backwards to the start of the current line, which is right after the previous `'\n'`,
+    /// or the start of the string if there isn't one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a\nbcd".as_slicer();
+    /// slicer.jump_to(4);
+    /// slicer.rewind_to_line_start();
+    /// assert_eq!(slicer.byte_pos(), 2);
+    /// ```
+    pub fn rewind_to_line_start(&mut self) {
+        let byte_pos = self.string[..self.byte_pos].rfind('\n').map_or(0, |newline_pos| newline_pos + 1);
+        unsafe {
+            self.jump_to_unchecked(byte_pos);
+        }
+    }
+
+    /// Skips to the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    pub fn skip_to_end(&mut self) {
+        unsafe {
+            let byte_pos = self.end_byte_pos();
+            self.jump_to_unchecked(byte_pos);
+        }
+    }
+    /// Skips to the end of the string, and returns the area skipped over as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
+    /// slicer.skip_until_after("skip over");
+    /// assert_eq!(slicer.slice_to_end(), Some(" entirely."));
+    /// ```
+    pub fn slice_to_end(&mut self) -> Option<&'str str> {
+        let start_pos = self.byte_pos;
+        if start_pos >= self.end_byte_pos() {
+            None
+        } else {
+            self.skip_to_end();
+            let end_pos = self.byte_pos;
+            Some(&self.string[start_pos..end_pos])
+        }
+    }
+    /// Same as [`slice_to_end`], except that trailing whitespace is trimmed off the returned slice.
+    ///
+    /// The slicer still skips to the actual end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "content\n\n  ".as_slicer();
+    /// assert_eq!(slicer.slice_to_end_trimmed(), Some("content"));
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`slice_to_end`]: struct.StrSlicer.html#method.slice_to_end
+    pub fn slice_to_end_trimmed(&mut self) -> Option<&'str str> {
+        self.slice_to_end().map(str::trim_end)
+    }
+    /// Checks whether or not the string slicer is at or past the end of the string it is operating on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    pub fn is_at_end(&self) -> bool {
+        self.byte_pos >= self.end_byte_pos()
+    }
+    /// Checks whether or not the string slicer is at the start of a line, meaning it's either
+    /// at the start of the string or right after a `'\n'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "a\nb".as_slicer();
+    /// assert_eq!(slicer.at_line_start(), true);
+    /// slicer.skip_over('a');
+    /// assert_eq!(slicer.at_line_start(), false);
+    /// slicer.skip_over('\n');
+    /// assert_eq!(slicer.at_line_start(), true);
+    /// ```
+    pub fn at_line_start(&self) -> bool {
+        self.byte_pos == 0 || self.string.as_bytes().get(self.byte_pos - 1) == Some(&b'\n')
+    }
+    
+    /// Turns the slicer into an iterator over the key/value pairs in the rest of the string,
+    /// see [`Pairs`].
+    ///
+    /// Entries are separated by `entry_sep`, and each entry is split into a key and a value
+    /// at the first `kv_sep`. If an entry doesn't contain `kv_sep`, the whole entry is
+    /// yielded as the key, with an empty value. A trailing `entry_sep` doesn't produce an
+    /// extra empty entry, but empty entries anywhere else, such as from a leading `entry_sep`
+    /// or two `entry_sep`s in a row, are yielded as `("", "")`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let pairs: Vec<_> = "a=1;b=2".as_slicer().pairs('=', ';').collect();
+    /// assert_eq!(pairs, [("a", "1"), ("b", "2")]);
+    ///
+    /// let pairs: Vec<_> = "flag;key=value;".as_slicer().pairs('=', ';').collect();
+    /// assert_eq!(pairs, [("flag", ""), ("key", "value")]);
+    ///
+    /// let pairs: Vec<_> = "a=1;;b=2".as_slicer().pairs('=', ';').collect();
+    /// assert_eq!(pairs, [("a", "1"), ("", ""), ("b", "2")]);
+    ///
+    /// let pairs: Vec<_> = ";a=1".as_slicer().pairs('=', ';').collect();
+    /// assert_eq!(pairs, [("", ""), ("a", "1")]);
+    /// ```
+    ///
+    /// [`Pairs`]: iter/struct.Pairs.html
+    pub fn pairs(self, kv_sep: char, entry_sep: char) -> iter::Pairs<'str, T> {
+        iter::Pairs {
+            slicer: self,
+            kv_sep,
+            entry_sep
+        }
+    }
+    /// Turns the slicer into an iterator over the whitespace-separated words in the rest of the
+    /// string, see [`Words`].
+    ///
+    /// Works like [`str::split_whitespace`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.split_whitespace):
+    /// any Unicode whitespace separates words, and runs of whitespace never produce empty words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let words: Vec<_> = "  foo   bar baz ".as_slicer().words().collect();
+    /// assert_eq!(words, ["foo", "bar", "baz"]);
+    /// ```
+    ///
+    /// [`Words`]: iter/struct.Words.html
+    pub fn words(self) -> iter::Words<'str, T> {
+        iter::Words {
+            slicer: self
+        }
+    }
+    /// Turns the slicer into an iterator over the rest of the lines, each one including its line terminator,
+    /// see [`RawLines`].
+    ///
+    /// Unlike [`slice_line`], both `\n` and `\r\n` are kept, so joining the lines back together gives the original string.
+    /// The last line doesn't have a terminator if the string doesn't end with one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let lines: Vec<_> = "a\r\nb\n".as_slicer().raw_lines().collect();
+    /// assert_eq!(lines, ["a\r\n", "b\n"]);
+    ///
+    /// let lines: Vec<_> = "a\nb".as_slicer().raw_lines().collect();
+    /// assert_eq!(lines, ["a\n", "b"]);
+    /// ```
+    ///
+    /// [`RawLines`]: iter/struct.RawLines.html
+    /// [`slice_line`]: struct.StrSlicer.html#method.slice_line
+    pub fn raw_lines(self) -> iter::RawLines<'str, T> {
+        iter::RawLines {
+            slicer: self
+        }
+    }
+    /// Turns the slicer into an iterator over the rest of the string split by `pattern`,
+    /// going from the end of the string back towards the slicer's position, see [`RSplit`].
+    ///
+    /// Yields the same fields as [`str::rsplit`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.rsplit),
+    /// so a trailing `pattern` produces an empty first field, and a leading one an empty last field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let fields: Vec<_> = "a.b.c".as_slicer().rsplit('.').collect();
+    /// assert_eq!(fields, ["c", "b", "a"]);
+    ///
+    /// let fields: Vec<_> = "a.b.".as_slicer().rsplit('.').collect();
+    /// assert_eq!(fields, ["", "b", "a"]);
+    /// ```
+    ///
+    /// [`RSplit`]: iter/struct.RSplit.html
+    pub fn rsplit(mut self, pattern: char) -> iter::RSplit<'str, T> {
+        let start_pos = self.byte_pos;
+        self.skip_to_end();
+        iter::RSplit {
+            slicer: self,
+            pattern,
+            start_pos,
+            finished: false
+        }
+    }
+    /// Turns the slicer into an iterator over the rest of the string split by `pattern`, where each
+    /// field is paired with the tracker's position at its start, see [`SpannedSplit`].
+    ///
+    /// The pattern is cloned for every field, so it has to implement `Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let slicer = "a\nb\nc".as_slicer_with_tracker(LineTracker::new());
+    /// let fields: Vec<_> = slicer.spanned_split('\n').collect();
+    /// assert_eq!(fields, [("a", 0), ("b", 1), ("c", 2)]);
+    /// ```
+    ///
+    /// A zero-width pattern, such as [`LineStart`], never produces empty fields:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::LineStart;
+    ///
+    /// let fields: Vec<_> = "a\nb".as_slicer().spanned_split(LineStart).collect();
+    /// assert_eq!(fields, [("a\n", ()), ("b", ())]);
+    /// ```
+    ///
+    /// [`SpannedSplit`]: iter/struct.SpannedSplit.html
+    /// [`LineStart`]: patterns/struct.LineStart.html
+    pub fn spanned_split<P: Pattern + Clone>(self, pattern: P) -> iter::SpannedSplit<'str, T, P> {
+        iter::SpannedSplit {
+            slicer: self,
+            pattern
+        }
+    }
+    /// Turns the slicer into an iterator over the byte ranges of the fields in the rest of the string
+    /// split by `pattern`, see [`SplitRanges`].
+    ///
+    /// Fields are found the same way as with [`spanned_split`], and the ranges are relative to the start of the whole string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::LineStart;
+    ///
+    /// let ranges: Vec<_> = "ab,cd".as_slicer().split_ranges(',').collect();
+    /// assert_eq!(ranges, [0..2, 3..5]);
+    ///
+    /// //zero-width patterns never produce empty fields
+    /// let ranges: Vec<_> = "a\nb".as_slicer().split_ranges(LineStart).collect();
+    /// assert_eq!(ranges, [0..2, 2..3]);
+    /// ```
+    ///
+    /// [`SplitRanges`]: iter/struct.SplitRanges.html
+    /// [`spanned_split`]: struct.StrSlicer.html#method.spanned_split
+    pub fn split_ranges<P: Pattern + Clone>(self, pattern: P) -> iter::SplitRanges<'str, T, P> {
+        iter::SplitRanges {
+            slicer: self,
+            pattern
+        }
+    }
+    /// Turns the slicer into an iterator over the records in the rest of the string, where each record
+    /// ends after `terminator`, see [`Records`].
+    ///
+    /// Unlike splitting, the terminator is kept at the end of each record, so a truncated
+    /// final record can be told apart by it not ending in the terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let records: Vec<_> = "a;b;".as_slicer().records(';').collect();
+    /// assert_eq!(records, ["a;", "b;"]);
+    ///
+    /// let records: Vec<_> = "a;b".as_slicer().records(';').collect();
+    /// assert_eq!(records, ["a;", "b"]);
+    /// ```
+    ///
+    /// A zero-width terminator, such as [`LineStart`], never produces empty records:
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::LineStart;
+    ///
+    /// let records: Vec<_> = "a\nb".as_slicer().records(LineStart).collect();
+    /// assert_eq!(records, ["a\n", "b"]);
+    /// ```
+    ///
+    /// [`Records`]: iter/struct.Records.html
+    /// [`LineStart`]: patterns/struct.LineStart.html
+    pub fn records<P: Pattern + Clone>(self, terminator: P) -> iter::Records<'str, T, P> {
+        iter::Records {
+            slicer: self,
+            terminator
+        }
+    }
+    /// Returns an iterator over the byte indices of the upcoming matches of `pattern`, without
+    /// moving this slicer, see [`PeekMatches`].
+    ///
+    /// Matches don't overlap, the same as with [`str::match_indices`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.match_indices).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::LineStart;
+    ///
+    /// let mut slicer = "a,b,,c".as_slicer();
+    /// slicer.skip_over('a');
+    /// let offsets: Vec<_> = slicer.peek_matches(',').collect();
+    /// assert_eq!(offsets, [1, 3, 4]);
+    /// assert_eq!(slicer.byte_pos(), 1);
+    ///
+    /// let line_starts: Vec<_> = "a\nb\n".as_slicer().peek_matches(LineStart).collect();
+    /// assert_eq!(line_starts, [0, 2, 4]);
+    /// ```
+    ///
+    /// [`PeekMatches`]: iter/struct.PeekMatches.html
+    pub fn peek_matches<P: Pattern>(&self, pattern: P) -> iter::PeekMatches<'str, P> {
+        iter::PeekMatches {
+            probe: self.probe(),
+            pattern,
+            finished: false
+        }
+    }
+    
+    /// Saves the slicer's current position, along with a snapshot of its [`Tracker`], so that
+    /// it can be gone back to later with [`restore`].
+    ///
+    /// Only available for trackers that implement [`SnapshotTracker`]. For other trackers,
+    /// use [`save_pos`] and [`load_pos`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer_with_tracker(LineTracker::new());
+    /// slicer.skip_line();
+    /// let bookmark = slicer.bookmark();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), 2);
+    ///
+    /// slicer.restore(bookmark);
+    /// assert_eq!(slicer.byte_pos(), 7);
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    ///
+    /// //restoring gives the same tracker position as jumping back
+    /// let mut jumped = "Line 1\nLine 2\nLine 3".as_slicer_with_tracker(LineTracker::new());
+    /// jumped.skip_to_end();
+    /// jumped.jump_to(7);
+    /// assert_eq!(slicer.tracker_pos(), jumped.tracker_pos());
+    /// ```
+    ///
+    /// [`Tracker`]: trait.Tracker.html
+    /// [`SnapshotTracker`]: trait.SnapshotTracker.html
+    /// [`restore`]: struct.StrSlicer.html#method.restore
+    /// [`save_pos`]: struct.StrSlicer.html#method.save_pos
+    /// [`load_pos`]: struct.StrSlicer.html#method.load_pos
+    pub fn bookmark(&self) -> Bookmark<T::Snapshot> where T: SnapshotTracker {
+        Bookmark {
+            byte_pos: self.byte_pos,
+            snapshot: self.tracker.snapshot()
+        }
+    }
+    /// Goes back to a position saved with [`bookmark`].
+    ///
+    /// Instead of recomputing the [`Tracker`]'s position from a jump, the tracker's state is
+    /// restored from the snapshot saved in the bookmark, so this takes constant time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bookmark's position is not on a UTF-8 code point boundary, or if it is
+    /// beyond the end of the string slice, which can happen if the bookmark was made by a
+    /// slicer operating on a different string.
+    ///
+    /// [`Tracker`]: trait.Tracker.html
+    /// [`bookmark`]: struct.StrSlicer.html#method.bookmark
+    pub fn restore(&mut self, bookmark: Bookmark<T::Snapshot>) where T: SnapshotTracker {
+        if bookmark.byte_pos > self.end_byte_pos() {
+            jump_oob_fail(self.string, bookmark.byte_pos);
+        }
+        if !self.string.is_char_boundary(bookmark.byte_pos) {
+            jump_char_boundary_fail(self.string, bookmark.byte_pos);
+        }
+        self.byte_pos = bookmark.byte_pos;
+        self.tracker.restore(bookmark.snapshot);
+    }
+    /// Saves the slicer's current position as a plain byte index, so that it can be gone back to later with [`load_pos`].
+    ///
+    /// This is a lighter alternative to [`bookmark`] that doesn't snapshot the [`Tracker`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::LineTracker;
+    ///
+    /// let mut slicer = "Line 1\nLine 2\nLine 3".as_slicer_with_tracker(LineTracker::new());
+    /// slicer.skip_line();
+    /// let pos = slicer.save_pos();
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), 2);
+    ///
+    /// slicer.load_pos(pos);
+    /// assert_eq!(slicer.byte_pos(), 7);
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    /// ```
+    ///
+    /// [`load_pos`]: struct.StrSlicer.html#method.load_pos
+    /// [`bookmark`]: struct.StrSlicer.html#method.bookmark
+    /// [`Tracker`]: trait.Tracker.html
+    #[inline]
+    pub fn save_pos(&self) -> usize {
+        self.byte_pos
+    }
+    /// Goes back to a position saved with [`save_pos`].
+    ///
+    /// This is the same as [`jump_to`], so the [`Tracker`] updates its position from the jump
+    /// instead of being restored from a snapshot like with [`restore`].
+    ///
+    /// [`save_pos`]: struct.StrSlicer.html#method.save_pos
+    /// [`jump_to`]: struct.StrSlicer.html#method.jump_to
+    /// [`Tracker`]: trait.Tracker.html
+    /// [`restore`]: struct.StrSlicer.html#method.restore
+    pub fn load_pos(&mut self, pos: usize) {
+        self.jump_to(pos);
+    }
+    /// Creates a [`Cursor`] for chaining several steps on this slicer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "(x)".as_slicer();
+    /// let mut inner = "";
+    /// let ok = slicer.cursor()
+    ///     .skip('(')
+    ///     .slice_until(')', &mut inner)
+    ///     .skip(')')
+    ///     .is_ok();
+    /// assert_eq!(ok, true);
+    /// assert_eq!(inner, "x");
+    /// ```
+    ///
+    /// [`Cursor`]: struct.Cursor.html
+    pub fn cursor(&mut self) -> Cursor<'_, 'str, T> {
+        Cursor {
+            slicer: self,
+            failed_at: None
+        }
+    }
+    /// Wraps this slicer in a [`TrimmedSlicer`], whose slicing methods trim whitespace off of the slices they return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = " a , b ".as_slicer().trim_results();
+    /// let mut fields = Vec::new();
+    /// while let Some(field) = slicer.slice_until(',') {
+    ///     fields.push(field);
+    ///     slicer.skip_over(',');
+    /// }
+    /// assert_eq!(fields, ["a", "b"]);
+    /// ```
+    ///
+    /// [`TrimmedSlicer`]: struct.TrimmedSlicer.html
+    pub fn trim_results(self) -> TrimmedSlicer<'str, T> {
+        TrimmedSlicer {
+            slicer: self
+        }
+    }
+    /// Wraps this slicer in a [`HistorySlicer`], which keeps a stack of saved positions for backtracking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// let mut slicer = "let x".as_slicer().with_history();
+    /// slicer.push_pos();
+    /// if !slicer.skip_over("let!") {
+    ///     slicer.pop_pos();
+    /// }
+    /// assert_eq!(slicer.skip_over("let"), true);
+    /// ```
+    ///
+    /// [`HistorySlicer`]: struct.HistorySlicer.html
+    pub fn with_history(self) -> HistorySlicer<'str, T> {
+        HistorySlicer {
+            slicer: self,
+            history: Vec::new()
+        }
+    }
+}
+
+/// A wrapper around a [`StrSlicer`] whose methods can be chained, created by [`StrSlicer::cursor`].
+///
+/// Once a step fails, the byte index it failed at is recorded and all of the following steps do nothing,
+/// so the chain only has to be checked once at the end.
+///
+/// # Examples
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut slicer = "(x".as_slicer();
+/// let mut inner = "";
+/// let mut cursor = slicer.cursor();
+/// cursor.skip('(').slice_until(')', &mut inner).skip(')').skip_whitespace();
+/// assert_eq!(cursor.failed_at(), Some(2));
+/// assert_eq!(inner, "x");
+/// ```
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`StrSlicer::cursor`]: struct.StrSlicer.html#method.cursor
+#[derive(Debug)]
+pub struct Cursor<'a, 'str: 'a, T: Tracker + 'a> {
+    slicer: &'a mut StrSlicer<'str, T>,
+    failed_at: Option<usize>
+}
+impl<'a, 'str, T: Tracker> Cursor<'a, 'str, T> {
+    fn fail(&mut self) {
+        self.failed_at = Some(self.slicer.byte_pos);
+    }
+    /// Skips over the given [`Pattern`], failing if it isn't next. See [`StrSlicer::skip_over`].
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    /// [`StrSlicer::skip_over`]: struct.StrSlicer.html#method.skip_over
+    pub fn skip<P: Pattern>(&mut self, pattern: P) -> &mut Self {
+        if self.is_ok() && !self.slicer.skip_over(pattern) {
+            self.fail();
+        }
+        self
+    }
+    /// Slices until the given [`Pattern`], storing the slice in `out`, failing if the slicer is at the end.
+    /// See [`StrSlicer::slice_until`].
+    ///
+    /// [`Pattern`]: trait.Pattern.html
+    /// [`StrSlicer::slice_until`]: struct.StrSlicer.html#method.slice_until
+    pub fn slice_until<P: Pattern>(&mut self, pattern: P, out: &mut &'str str) -> &mut Self {
+        if self.is_ok() {
+            match self.slicer.slice_until(pattern) {
+                Some(slice) => *out = slice,
+                None => self.fail()
+            }
+        }
+        self
+    }
+    /// Skips over any whitespace, which never fails. See [`StrSlicer::skip_whitespace`].
+    ///
+    /// [`StrSlicer::skip_whitespace`]: struct.StrSlicer.html#method.skip_whitespace
+    pub fn skip_whitespace(&mut self) -> &mut Self {
+        if self.is_ok() {
+            self.slicer.skip_whitespace();
+        }
+        self
+    }
+    /// Checks whether or not all of the steps so far succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.failed_at.is_none()
+    }
+    /// Returns the byte index the first failed step failed at, or `None` if none of the steps failed.
+    pub fn failed_at(&self) -> Option<usize> {
+        self.failed_at
+    }
+}
+
+/// A wrapper around a [`StrSlicer`] that trims whitespace off of the slices it returns, created by [`StrSlicer::trim_results`].
+///
+/// Only [`slice_until`], [`slice_rest_until`] and [`slice_to_end`] are trimmed; the slicer still
+/// moves over the untrimmed area, so positions are the same as without the wrapper.
+/// All of the other `StrSlicer` methods are available through `Deref`, and return their results untrimmed.
+///
+/// # Examples
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut slicer = "key = value ".as_slicer().trim_results();
+/// assert_eq!(slicer.slice_until('='), Some("key"));
+/// assert_eq!(slicer.byte_pos(), 4);
+/// slicer.skip_over('=');
+/// assert_eq!(slicer.slice_to_end(), Some("value"));
+/// ```
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`StrSlicer::trim_results`]: struct.StrSlicer.html#method.trim_results
+/// [`slice_until`]: struct.TrimmedSlicer.html#method.slice_until
+/// [`slice_rest_until`]: struct.TrimmedSlicer.html#method.slice_rest_until
+/// [`slice_to_end`]: struct.TrimmedSlicer.html#method.slice_to_end
+#[derive(Debug, Clone, Copy)]
+pub struct TrimmedSlicer<'str, T: Tracker = ()> {
+    slicer: StrSlicer<'str, T>
+}
+impl<'str, T: Tracker> TrimmedSlicer<'str, T> {
+    /// Same as [`StrSlicer::slice_until`], except that the slice is trimmed.
+    ///
+    /// [`StrSlicer::slice_until`]: struct.StrSlicer.html#method.slice_until
+    pub fn slice_until<P: Pattern>(&mut self, pattern: P) -> Option<&'str str> {
+        self.slicer.slice_until(pattern).map(str::trim)
+    }
+    /// Same as [`StrSlicer::slice_rest_until`], except that the slice is trimmed.
+    ///
+    /// [`StrSlicer::slice_rest_until`]: struct.StrSlicer.html#method.slice_rest_until
+    pub fn slice_rest_until<P: Pattern>(&mut self, pattern: P) -> &'str str {
+        self.slicer.slice_rest_until(pattern).trim()
+    }
+    /// Same as [`StrSlicer::slice_to_end`], except that the slice is trimmed.
+    ///
+    /// [`StrSlicer::slice_to_end`]: struct.StrSlicer.html#method.slice_to_end
+    pub fn slice_to_end(&mut self) -> Option<&'str str> {
+        self.slicer.slice_to_end().map(str::trim)
+    }
+    /// Unwraps the underlying slicer.
+    pub fn into_inner(self) -> StrSlicer<'str, T> {
+        self.slicer
+    }
+}
+impl<'str, T: Tracker> Deref for TrimmedSlicer<'str, T> {
+    type Target = StrSlicer<'str, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.slicer
+    }
+}
+impl<'str, T: Tracker> DerefMut for TrimmedSlicer<'str, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.slicer
+    }
+}
+
+/// A wrapper around a [`StrSlicer`] that keeps a stack of saved positions, created by [`StrSlicer::with_history`].
+///
+/// This is useful for parsers that backtrack through many levels, such as PEG parsers: each attempt
+/// pushes a position with [`push_pos`], and then either goes back to it with [`pop_pos`] or keeps
+/// its progress with [`drop_pos`]. Going back is the same as [`StrSlicer::jump_to`], so the tracker
+/// updates its position from the jump. All of the `StrSlicer` methods are available through `Deref`.
+///
+/// # Examples
+///
+/// ```
+/// # use slicer::AsSlicer;
+/// let mut slicer = "abcdef".as_slicer().with_history();
+/// for _ in 0..3 {
+///     slicer.push_pos();
+///     slicer.skip_num_chars(2);
+/// }
+/// assert_eq!(slicer.byte_pos(), 6);
+/// assert_eq!(slicer.depth(), 3);
+///
+/// assert_eq!(slicer.pop_pos(), Some(4));
+/// assert_eq!(slicer.byte_pos(), 4);
+/// assert_eq!(slicer.pop_pos(), Some(2));
+/// assert_eq!(slicer.byte_pos(), 2);
+/// assert_eq!(slicer.pop_pos(), Some(0));
+/// assert_eq!(slicer.byte_pos(), 0);
+/// assert_eq!(slicer.pop_pos(), None);
+/// ```
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`StrSlicer::with_history`]: struct.StrSlicer.html#method.with_history
+/// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
+/// [`push_pos`]: struct.HistorySlicer.html#method.push_pos
+/// [`pop_pos`]: struct.HistorySlicer.html#method.pop_pos
+/// [`drop_pos`]: struct.HistorySlicer.html#method.drop_pos
+#[derive(Debug, Clone)]
+pub struct HistorySlicer<'str, T: Tracker = ()> {
+    slicer: StrSlicer<'str, T>,
+    history: Vec<usize>
+}
+impl<'str, T: Tracker> HistorySlicer<'str, T> {
+    /// Saves the slicer's current position on top of the stack.
+    pub fn push_pos(&mut self) {
+        self.history.push(self.slicer.byte_pos);
+    }
+    /// Takes the most recently saved position off of the stack and jumps back to it, returning it.
+    ///
+    /// Returns `None` and doesn't move if the stack is empty.
+    pub fn pop_pos(&mut self) -> Option<usize> {
+        let byte_pos = self.history.pop()?;
+        self.slicer.jump_to(byte_pos);
+        Some(byte_pos)
+    }
+    /// Takes the most recently saved position off of the stack without jumping back to it, returning it.
+    pub fn drop_pos(&mut self) -> Option<usize> {
+        self.history.pop()
+    }
+    /// Returns the number of positions saved on the stack.
+    pub fn depth(&self) -> usize {
+        self.history.len()
+    }
+    /// Unwraps the underlying slicer, discarding the saved positions.
+    pub fn into_inner(self) -> StrSlicer<'str, T> {
+        self.slicer
+    }
+}
+impl<'str, T: Tracker> Deref for HistorySlicer<'str, T> {
+    type Target = StrSlicer<'str, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.slicer
+    }
+}
+impl<'str, T: Tracker> DerefMut for HistorySlicer<'str, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.slicer
+    }
+}
+
+/// The error returned when a [`StrSlicer`] method needed more input, but the slicer was at the end of the string.
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndOfInput {
+    /// The byte index the slicer was at.
+    pub byte_pos: usize
+}
+impl fmt::Display for EndOfInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected end of input at byte index {}", self.byte_pos)
+    }
+}
+impl Error for EndOfInput {}
+
+/// A position within a string, returned by [`StrSlicer::location`].
+///
+/// Displays as `line:column`.
+///
+/// [`StrSlicer::location`]: struct.StrSlicer.html#method.location
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The byte index.
+    pub byte: usize,
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column number, counted in chars.
+    pub column: usize
+}
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A builder for configuring a [`StrSlicer`] before it's created, created by [`StrSlicer::builder`].
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`StrSlicer::builder`]: struct.StrSlicer.html#method.builder
+#[derive(Debug, Clone, Copy)]
+pub struct SlicerBuilder<'str, T: Tracker = ()> {
+    string: &'str str,
+    offset: usize,
+    scan_limit: Option<usize>,
+    tracker: T
+}
+impl<'str, T: Tracker> SlicerBuilder<'str, T> {
+    /// Sets the [`Tracker`] the slicer will use.
+    ///
+    /// [`Tracker`]: trait.Tracker.html
+    pub fn tracker<U: Tracker>(self, tracker: U) -> SlicerBuilder<'str, U> {
+        SlicerBuilder {
+            string: self.string,
+            offset: self.offset,
+            scan_limit: self.scan_limit,
+            tracker
+        }
+    }
+    /// Sets the byte index the slicer will start at. The tracker is updated as if the
+    /// slicer had jumped there from the start of the string.
+    pub fn offset(mut self, byte_pos: usize) -> Self {
+        self.offset = byte_pos;
+        self
+    }
+    /// Sets the slicer's scan limit, see [`StrSlicer::set_scan_limit`].
+    ///
+    /// [`StrSlicer::set_scan_limit`]: struct.StrSlicer.html#method.set_scan_limit
+    pub fn scan_limit(mut self, max_bytes: Option<usize>) -> Self {
+        self.scan_limit = max_bytes;
+        self
+    }
+    /// Creates the configured slicer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is out of bounds or not on a UTF-8 code point boundary, the same way [`StrSlicer::jump_to`] does.
+    ///
+    /// ```should_panic
+    /// # use slicer::StrSlicer;
+    /// let slicer = StrSlicer::builder("🌺").offset(2).build();
+    /// ```
+    ///
+    /// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
+    pub fn build(self) -> StrSlicer<'str, T> {
+        let mut slicer = StrSlicer::with_tracker(self.string, self.tracker);
+        slicer.set_scan_limit(self.scan_limit);
+        slicer.jump_to(self.offset);
+        slicer
+    }
+}
+
+/// A saved position of a [`StrSlicer`], created by [`StrSlicer::bookmark`].
+///
+/// [`StrSlicer`]: struct.StrSlicer.html
+/// [`StrSlicer::bookmark`]: struct.StrSlicer.html#method.bookmark
+#[derive(Debug, Clone, Copy)]
+pub struct Bookmark<S> {
+    byte_pos: usize,
+    snapshot: S
+}
+impl<S> Bookmark<S> {
+    /// Returns the byte index that this bookmark was made at.
+    #[inline]
+    pub fn byte_pos(&self) -> usize {
+        self.byte_pos
+    }
+}
+
+impl<'str, T: Tracker> AsRef<str> for StrSlicer<'str, T> {
+    fn as_ref(&self) -> &str {
+        self.string
+    }
+}
+/// Creates a slicer over the empty string, which is already at its end.
+///
+/// ```
+/// use slicer::StrSlicer;
+///
+/// let slicer = StrSlicer::default();
+/// assert_eq!(slicer.is_at_end(), true);
+/// assert_eq!(slicer.as_str(), "");
+/// ```
+impl Default for StrSlicer<'static, ()> {
+    fn default() -> Self {
+        StrSlicer::new("")
+    }
+}
+
+/// Used by [`StrSlicer::skip_to_indent_at_most`]
+///
+/// [`StrSlicer::skip_to_indent_at_most`]: struct.StrSlicer.html#method.skip_to_indent_at_most
+//measures the leading whitespace of `line` in columns, with tabs moving to the next tab stop.
+fn indentation_width(line: &str, tab_width: usize) -> usize {
+    let mut width = 0;
+    for char in line.chars() {
+        match char {
+            ' ' | '\t' => width = advance_column(width, char, tab_width),
+            _ => break
+        }
+    }
+    width
+}
+
+/// Used by [`StrSlicer::line_and_column_with_tabs`] and [`indentation_width`]
+///
+/// [`StrSlicer::line_and_column_with_tabs`]: struct.StrSlicer.html#method.line_and_column_with_tabs
+/// [`indentation_width`]: fn.indentation_width.html
+//returns the 0-based column after `char`, with tabs moving to the next tab stop.
+fn advance_column(column: usize, char: char, tab_width: usize) -> usize {
+    match char {
+        '\t' => column + tab_width - column % tab_width.max(1),
+        _ => column + 1
+    }
+}
+
+/// Used by [`StrSlicer::num_remaining_lines`] and [`LineTracker`]
+///
+/// [`StrSlicer::num_remaining_lines`]: struct.StrSlicer.html#method.num_remaining_lines
+/// [`LineTracker`]: trackers/struct.LineTracker.html
+//counts the newlines in `string`, and finds the index of the last one.
+//with the `memchr` feature enabled, this searches the bytes with `memchr`, which is much faster on long strings.
+#[cfg(feature = "memchr")]
+fn count_newlines(string: &str) -> (usize, Option<usize>) {
+    let bytes = string.as_bytes();
+    (memchr::memchr_iter(b'\n', bytes).count(), memchr::memrchr(b'\n', bytes))
+}
+//same as above, for when the `memchr` feature is disabled.
+#[cfg(not(feature = "memchr"))]
+fn count_newlines(string: &str) -> (usize, Option<usize>) {
+    let mut count = 0;
+    let mut last = None;
+    for (index, _) in string.match_indices('\n') {
+        count += 1;
+        last = Some(index);
+    }
+    (count, last)
+}
+
+/// Used by `jump_oob_fail` and `jump_char_boundary_fail`
+//truncate `&str` to length at most equal to `max`,
+//return `true` if it were truncated, and the new str.
+//basically copied from the truncate_to_char_boundary function in libcore/str/mod.rs
+fn truncate_to_char_boundary(s: &str, mut max: usize) -> (bool, &str) {
+    if max >= s.len() {
+        (false, s)
+    } else {
+        while !s.is_char_boundary(max) {
+            max -= 1;
+        }
+        (true, &s[..max])
+    }
+}
+
+/// Function that panics for out-of-bound errors in [`StrSlicer::jump_to`]
+///
+/// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
+//basically copied from the slice_error_fail function in libcore/str/mod.rs
+#[inline(never)]
+#[cold]
+fn jump_oob_fail(string: &str, byte_pos: usize) -> ! {
+    const MAX_DISPLAY_LENGTH: usize = 256;
+    let (truncated, s_trunc) = truncate_to_char_boundary(string, MAX_DISPLAY_LENGTH);
+    let ellipsis = if truncated { "[...]" } else { "" };
+
+    panic!("byte index {} is out of bounds of `{}`{}", byte_pos, s_trunc, ellipsis);
+}
+
+/// Function that panics for jumping to a position that is not a UTF-8
+/// char boundary in [`StrSlicer::jump_to`]
+///
+/// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
+//basically copied from the slice_error_fail function in libcore/str/mod.rs
+#[inline(never)]
+#[cold]
+fn jump_char_boundary_fail(string: &str, byte_pos: usize) -> ! {
+    const MAX_DISPLAY_LENGTH: usize = 256;
+    let (truncated, s_trunc) = truncate_to_char_boundary(string, MAX_DISPLAY_LENGTH);
+    let ellipsis = if truncated { "[...]" } else { "" };
+    
+    //find the start index of the character byte_pos is inside of
+    let mut char_start = byte_pos;
+    while !string.is_char_boundary(char_start) {
+        char_start -= 1;
+    }
+    
+    //`char_start` must be less than len and a char boundary
+    let char = string[char_start..].chars().next().unwrap();
+    let char_byte_range = char_start..(char_start + char.len_utf8());
+    
+    panic!("byte index {} is not a char boundary; it is inside {:?} (bytes {:?}) of `{}`{}",
+           byte_pos, char, char_byte_range, s_trunc, ellipsis);
+}
+
+/// A module containing the iterator types returned by [`StrSlicer`]'s methods.
+///
+/// [`StrSlicer`]: ../struct.StrSlicer.html
+pub mod iter {
+    use std::ops::Range;
+    use ::{AsSlicer, Pattern, StrSlicer, Tracker};
+    
+    /// An iterator over key/value pairs, created by [`StrSlicer::pairs`].
+    ///
+    /// [`StrSlicer::pairs`]: ../struct.StrSlicer.html#method.pairs
+    #[derive(Debug, Clone)]
+    pub struct Pairs<'str, T: Tracker> {
+        pub(crate) slicer: StrSlicer<'str, T>,
+        pub(crate) kv_sep: char,
+        pub(crate) entry_sep: char
+    }
+    impl<'str, T: Tracker> Iterator for Pairs<'str, T> {
+        type Item = (&'str str, &'str str);
+        fn next(&mut self) -> Option<Self::Item> {
+            let entry = self.slicer.slice_until(self.entry_sep)?;
+            self.slicer.skip_over(self.entry_sep);
+            
+            let mut entry_slicer = entry.as_slicer();
+            let key = entry_slicer.slice_until(self.kv_sep).unwrap_or("");
+            entry_slicer.skip_over(self.kv_sep);
+            let value = entry_slicer.slice_to_end().unwrap_or("");
+            Some((key, value))
+        }
+    }
+    
+    /// An iterator over whitespace-separated words, created by [`StrSlicer::words`].
+    ///
+    /// [`StrSlicer::words`]: ../struct.StrSlicer.html#method.words
+    #[derive(Debug, Clone)]
+    pub struct Words<'str, T: Tracker> {
+        pub(crate) slicer: StrSlicer<'str, T>
+    }
+    impl<'str, T: Tracker> Iterator for Words<'str, T> {
+        type Item = &'str str;
+        fn next(&mut self) -> Option<Self::Item> {
+            self.slicer.skip_whitespace();
+            self.slicer.slice_non_whitespace()
+        }
+    }
+    
+    /// An iterator over lines that keeps their line terminators, created by [`StrSlicer::raw_lines`].
+    ///
+    /// [`StrSlicer::raw_lines`]: ../struct.StrSlicer.html#method.raw_lines
+    #[derive(Debug, Clone)]
+    pub struct RawLines<'str, T: Tracker> {
+        pub(crate) slicer: StrSlicer<'str, T>
+    }
+    impl<'str, T: Tracker> Iterator for RawLines<'str, T> {
+        type Item = &'str str;
+        fn next(&mut self) -> Option<Self::Item> {
+            self.slicer.slice_until_after('\n')
+        }
+    }
+    
+    /// An iterator over fields split by a char, going backwards from the end of the string,
+    /// created by [`StrSlicer::rsplit`].
+    ///
+    /// The underlying slicer moves backwards as fields are yielded, and ends up back on
+    /// its original position once the iterator is exhausted.
+    ///
+    /// [`StrSlicer::rsplit`]: ../struct.StrSlicer.html#method.rsplit
+    #[derive(Debug, Clone)]
+    pub struct RSplit<'str, T: Tracker> {
+        pub(crate) slicer: StrSlicer<'str, T>,
+        pub(crate) pattern: char,
+        pub(crate) start_pos: usize,
+        pub(crate) finished: bool
+    }
+    impl<'str, T: Tracker> Iterator for RSplit<'str, T> {
+        type Item = &'str str;
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished {
+                return None;
+            }
+            let string = self.slicer.string;
+            let end_pos = self.slicer.byte_pos;
+            let (field_pos, new_byte_pos) = match string[self.start_pos..end_pos].rfind(self.pattern) {
+                Some(offset) => {
+                    let delimiter_pos = self.start_pos + offset;
+                    (delimiter_pos + self.pattern.len_utf8(), delimiter_pos)
+                },
+                None => {
+                    self.finished = true;
+                    (self.start_pos, self.start_pos)
+                }
+            };
+            unsafe {
+                self.slicer.jump_to_unchecked(new_byte_pos);
+            }
+            Some(&string[field_pos..end_pos])
+        }
+    }
+    
+    /// An iterator over fields paired with the tracker's position at their start,
+    /// created by [`StrSlicer::spanned_split`].
+    ///
+    /// [`StrSlicer::spanned_split`]: ../struct.StrSlicer.html#method.spanned_split
+    #[derive(Debug, Clone)]
+    pub struct SpannedSplit<'str, T: Tracker, P: Pattern + Clone> {
+        pub(crate) slicer: StrSlicer<'str, T>,
+        pub(crate) pattern: P
+    }
+    impl<'str, T: Tracker, P: Pattern + Clone> Iterator for SpannedSplit<'str, T, P> {
+        type Item = (&'str str, T::Pos);
+        fn next(&mut self) -> Option<Self::Item> {
+            let start_pos = self.slicer.byte_pos;
+            if start_pos >= self.slicer.end_byte_pos() {
+                return None;
+            }
+            let pos = self.slicer.tracker_pos();
+            skip_until_progress(&mut self.slicer, self.pattern.clone());
+            let field = &self.slicer.string[start_pos..self.slicer.byte_pos];
+            self.slicer.skip_over(self.pattern.clone());
+            Some((field, pos))
+        }
+    }
+    
+    /// An iterator over the byte ranges of fields, created by [`StrSlicer::split_ranges`].
+    ///
+    /// [`StrSlicer::split_ranges`]: ../struct.StrSlicer.html#method.split_ranges
+    #[derive(Debug, Clone)]
+    pub struct SplitRanges<'str, T: Tracker, P: Pattern + Clone> {
+        pub(crate) slicer: StrSlicer<'str, T>,
+        pub(crate) pattern: P
+    }
+    impl<'str, T: Tracker, P: Pattern + Clone> Iterator for SplitRanges<'str, T, P> {
+        type Item = Range<usize>;
+        fn next(&mut self) -> Option<Self::Item> {
+            let start_pos = self.slicer.byte_pos;
+            if start_pos >= self.slicer.end_byte_pos() {
+                return None;
+            }
+            skip_until_progress(&mut self.slicer, self.pattern.clone());
+            let end_pos = self.slicer.byte_pos;
+            self.slicer.skip_over(self.pattern.clone());
+            Some(start_pos..end_pos)
+        }
+    }
+    
+    /// An iterator over records that keep their terminator, created by [`StrSlicer::records`].
+    ///
+    /// [`StrSlicer::records`]: ../struct.StrSlicer.html#method.records
+    #[derive(Debug, Clone)]
+    pub struct Records<'str, T: Tracker, P: Pattern + Clone> {
+        pub(crate) slicer: StrSlicer<'str, T>,
+        pub(crate) terminator: P
+    }
+    impl<'str, T: Tracker, P: Pattern + Clone> Iterator for Records<'str, T, P> {
+        type Item = &'str str;
+        fn next(&mut self) -> Option<Self::Item> {
+            let start_pos = self.slicer.byte_pos;
+            if start_pos >= self.slicer.end_byte_pos() {
+                return None;
+            }
+            skip_until_progress(&mut self.slicer, self.terminator.clone());
+            self.slicer.skip_over(self.terminator.clone());
+            Some(&self.slicer.string[start_pos..self.slicer.byte_pos])
+        }
+    }
+    
+    /// An iterator over the byte indices of upcoming matches of a pattern, created by [`StrSlicer::peek_matches`].
+    ///
+    /// It walks over its own untracked copy of the slicer, so the original slicer is never moved.
+    ///
+    /// [`StrSlicer::peek_matches`]: ../struct.StrSlicer.html#method.peek_matches
+    #[derive(Debug, Clone)]
+    pub struct PeekMatches<'str, P: Pattern> {
+        pub(crate) probe: StrSlicer<'str>,
+        pub(crate) pattern: P,
+        pub(crate) finished: bool
+    }
+    impl<'str, P: Pattern> Iterator for PeekMatches<'str, P> {
+        type Item = usize;
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished || !self.probe.skip_until_found(&mut self.pattern) {
+                return None;
+            }
+            let byte_pos = self.probe.byte_pos;
+            unsafe {
+                self.pattern.skip_over_unchecked(&mut self.probe);
+            }
+            //make sure zero-width matches don't get found again
+            if self.probe.byte_pos == byte_pos {
+                if self.probe.is_at_end() {
+                    self.finished = true;
+                } else {
+                    self.probe.advance_char();
+                }
+            }
+            Some(byte_pos)
+        }
+    }
+    
+    //skips forward until `pattern` is next, like `StrSlicer::skip_until`, except that a zero-width match right
+    //where the slicer already is gets stepped over, so that splitting iterators always make progress.
+    fn skip_until_progress<'str, T: Tracker, P: Pattern>(slicer: &mut StrSlicer<'str, T>, mut pattern: P) {
+        if pattern.match_len(slicer) == Some(0) && !slicer.is_at_end() {
+            slicer.advance_char();
+        }
+        slicer.scan_until(&mut pattern);
+    }
+}
+
+/// A module containing [`Pattern`] types that don't correspond to a built-in type.
+///
+/// [`Pattern`]: ../trait.Pattern.html
+pub mod patterns {
+    use std::ops;
+    use ::{Pattern, StrSlicer, Tracker};
+    
+    /// A zero-width [`Pattern`] that is only next at the end of the string.
+    ///
+    /// Slicing until an `Eof` behaves the same as [`StrSlicer::slice_to_end`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Eof;
+    ///
+    /// let mut slicer = "abc".as_slicer();
+    /// assert_eq!(slicer.is_next(Eof), false);
+    /// assert_eq!(slicer.slice_until(Eof), Some("abc"));
+    /// assert_eq!(slicer.is_next(Eof), true);
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    /// [`StrSlicer::slice_to_end`]: ../struct.StrSlicer.html#method.slice_to_end
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Eof;
+    impl Pattern for Eof {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            slicer.is_at_end()
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            slicer.skip_to_end();
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, _slicer: &mut StrSlicer<'str, T>) {}
+    }
+    
+    /// A zero-width [`Pattern`] that is next whenever [`StrSlicer::at_line_start`] is true.
+    ///
+    /// Skipping until a `LineStart` moves the slicer to the start of the next line, or to
+    /// the end of the string if there are no more lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::LineStart;
+    ///
+    /// let mut slicer = "a\nb".as_slicer();
+    /// assert_eq!(slicer.is_next(LineStart), true);
+    /// slicer.skip_over('a');
+    /// slicer.skip_until(LineStart);
+    /// assert_eq!(slicer.byte_pos(), 2);
+    /// assert_eq!(slicer.cut_off(), Some("b"));
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    /// [`StrSlicer::at_line_start`]: ../struct.StrSlicer.html#method.at_line_start
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct LineStart;
+    impl Pattern for LineStart {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            slicer.at_line_start()
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            if !slicer.at_line_start() {
+                slicer.skip_line();
+            }
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, _slicer: &mut StrSlicer<'str, T>) {}
+    }
+    
+    /// A [`Pattern`] that matches a line terminator, either `\n` or `\r\n`, or the end of the string.
+    ///
+    /// Skipping over an `Eol` skips over the line terminator, or does nothing at the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Eol;
+    ///
+    /// let mut slicer = "a\r\nb\nc".as_slicer();
+    /// assert_eq!(slicer.slice_until(Eol), Some("a"));
+    /// assert_eq!(slicer.skip_over_len(Eol), Some(2));
+    /// assert_eq!(slicer.slice_until(Eol), Some("b"));
+    /// assert_eq!(slicer.skip_over_len(Eol), Some(1));
+    /// assert_eq!(slicer.slice_until(Eol), Some("c"));
+    /// assert_eq!(slicer.is_next(Eol), true);
+    /// assert_eq!(slicer.skip_over_len(Eol), Some(0));
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Eol;
+    impl Pattern for Eol {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            self.match_len(slicer).is_some()
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let cut_off = match slicer.cut_off() {
+                None => return, //return early, since the slicer is already at the end, which is an `Eol`
+                Some(cut_off) => cut_off
+            };
+            match cut_off.find('\n') {
+                None => slicer.skip_to_end(),
+                Some(offset) => {
+                    //stop before the `\r` of a `\r\n`, so that the whole terminator is skipped over
+                    let offset = if cut_off[..offset].ends_with('\r') { offset - 1 } else { offset };
+                    let byte_pos = slicer.byte_pos + offset;
+                    unsafe {
+                        slicer.jump_to_unchecked(byte_pos);
+                    }
+                }
+            }
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            if let Some(len) = self.match_len(slicer) {
+                let byte_pos = slicer.byte_pos + len;
+                slicer.jump_to_unchecked(byte_pos);
+            }
+        }
+        fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            match slicer.cut_off() {
+                None => Some(0),
+                Some(cut_off) if cut_off.starts_with('\n') => Some(1),
+                Some(cut_off) if cut_off.starts_with("\r\n") => Some(2),
+                Some(_) => None
+            }
+        }
+    }
+    
+    /// A [`Pattern`] that matches a single char the wrapped predicate accepts.
+    ///
+    /// It behaves the same as using the predicate directly, but it can be negated with the `!` operator,
+    /// which turns it into a [`Not`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Pred;
+    ///
+    /// let mut slicer = "   indented".as_slicer();
+    /// slicer.skip_until(!Pred(char::is_whitespace));
+    /// assert_eq!(slicer.cut_off(), Some("indented"));
+    /// slicer.skip_until(Pred(char::is_whitespace));
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    /// [`Not`]: struct.Not.html
+    #[derive(Debug, Clone, Copy)]
+    pub struct Pred<F: FnMut(char) -> bool>(pub F);
+    impl<F: FnMut(char) -> bool> Pattern for Pred<F> {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            self.0.is_next(slicer)
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            self.0.skip_until(slicer)
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            self.0.skip_over_unchecked(slicer)
+        }
+        fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            self.0.rskip_until_after(slicer)
+        }
+        fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            self.0.match_len(slicer)
+        }
+    }
+    impl<F: FnMut(char) -> bool> ops::Not for Pred<F> {
+        type Output = Not<F>;
+        fn not(self) -> Self::Output {
+            Not(self.0)
+        }
+    }
+    
+    /// A [`Pattern`] that matches a single char the wrapped predicate rejects.
+    ///
+    /// Negating it with the `!` operator turns it back into a [`Pred`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Not;
+    ///
+    /// let mut slicer = "123abc".as_slicer();
+    /// assert_eq!(slicer.slice_until(Not(|c: char| c.is_ascii_digit())), Some("123"));
+    /// assert_eq!(slicer.skip_over(Not(|c: char| c.is_ascii_digit())), true);
+    /// assert_eq!(slicer.cut_off(), Some("bc"));
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    /// [`Pred`]: struct.Pred.html
+    #[derive(Debug, Clone, Copy)]
+    pub struct Not<F: FnMut(char) -> bool>(pub F);
+    impl<F: FnMut(char) -> bool> Pattern for Not<F> {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            let predicate = &mut self.0;
+            (|char| !predicate(char)).is_next(slicer)
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let predicate = &mut self.0;
+            (|char| !predicate(char)).skip_until(slicer)
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            slicer.advance_char();
+        }
+        fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let predicate = &mut self.0;
+            (|char| !predicate(char)).rskip_until_after(slicer)
+        }
+    }
+    impl<F: FnMut(char) -> bool> ops::Not for Not<F> {
+        type Output = Pred<F>;
+        fn not(self) -> Self::Output {
+            Pred(self.0)
+        }
+    }
+    
+    /// A [`Pattern`] that matches a single char within an inclusive range, from `.0` to `.1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::CharRange;
+    ///
+    /// let mut slicer = "ABC def".as_slicer();
+    /// slicer.skip_until(CharRange('a', 'z'));
+    /// assert_eq!(slicer.cut_off(), Some("def"));
+    /// assert_eq!(slicer.is_next(CharRange('d', 'd')), true);
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CharRange(pub char, pub char);
+    impl CharRange {
+        /// Checks whether or not the given char is within this range.
+        pub fn contains(&self, char: char) -> bool {
+            self.0 <= char && char <= self.1
+        }
+    }
+    impl Pattern for CharRange {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            let range = *self;
+            (|char| range.contains(char)).is_next(slicer)
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let range = *self;
+            (|char| range.contains(char)).skip_until(slicer)
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            slicer.advance_char();
+        }
+        fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let range = *self;
+            (|char| range.contains(char)).rskip_until_after(slicer)
+        }
+    }
+    
+    /// A [`Pattern`] that matches the next `.0` bytes, for slicing fixed-width fields.
+    ///
+    /// It is next whenever at least `.0` bytes are left. If the last of those bytes is in the middle
+    /// of a UTF-8 code point, skipping over it goes on to the end of that code point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Bytes;
+    ///
+    /// let mut slicer = "abcdef🌺".as_slicer();
+    /// assert_eq!(slicer.slice_over(Bytes(3)), Some("abc"));
+    /// assert_eq!(slicer.slice_over(Bytes(4)), Some("def🌺"));
+    /// assert_eq!(slicer.is_next(Bytes(1)), false);
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Bytes(pub usize);
+    impl Pattern for Bytes {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            slicer.end_byte_pos().saturating_sub(slicer.byte_pos) >= self.0
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            //if there aren't enough bytes left here, there won't be any further on either
+            if !self.is_next(slicer) {
+                slicer.skip_to_end();
+            }
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let mut byte_pos = slicer.byte_pos + self.0;
+            while !slicer.string.is_char_boundary(byte_pos) {
+                byte_pos += 1;
+            }
+            slicer.jump_to_unchecked(byte_pos);
+        }
+    }
+    
+    /// A [`Pattern`] that matches the wrapped string slice only as a whole word, meaning it isn't directly
+    /// followed by an identifier char (an alphanumeric char or `_`).
+    ///
+    /// Only what comes after the string is checked. Skipping over a `Word` skips over the string itself,
+    /// not the char after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::patterns::Word;
+    ///
+    /// assert_eq!("for x".as_slicer().is_next(Word("for")), true);
+    /// assert_eq!("for".as_slicer().is_next(Word("for")), true);
+    /// assert_eq!("format".as_slicer().is_next(Word("for")), false);
+    ///
+    /// let mut slicer = "format for".as_slicer();
+    /// slicer.skip_until(Word("for"));
+    /// assert_eq!(slicer.byte_pos(), 7);
+    /// assert_eq!(slicer.skip_over(Word("for")), true);
+    /// assert_eq!(slicer.is_at_end(), true);
+    /// ```
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Word<'a>(pub &'a str);
+    impl<'a> Pattern for Word<'a> {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            self.match_len(slicer).is_some()
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let mut probe = slicer.probe();
+            loop {
+                self.0.skip_until(&mut probe);
+                if probe.is_at_end() {
+                    slicer.skip_to_end();
+                    return;
+                }
+                if self.is_next(&probe) {
+                    unsafe {
+                        slicer.jump_to_unchecked(probe.byte_pos);
+                    }
+                    return;
+                }
+                //the string was found as part of a longer word, so look for it again after this spot
+                probe.advance_char();
+            }
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let byte_pos = slicer.byte_pos + self.0.len();
+            slicer.jump_to_unchecked(byte_pos);
+        }
+        fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            let len = self.0.match_len(slicer)?;
+            let after = &slicer.string[slicer.byte_pos + len..];
+            match after.chars().next() {
+                Some(char) if char.is_alphanumeric() || char == '_' => None,
+                _ => Some(len)
+            }
+        }
+    }
+    
+    /// A [`Pattern`] that matches any of many string slices, searching for all of them at once
+    /// with the [`aho-corasick`](https://docs.rs/aho-corasick) crate.
+    ///
+    /// Only available with the `aho-corasick` feature enabled.
+    ///
+    /// It behaves the same as a slice of string slices: the earliest match wins, and if several strings
+    /// match at the same position, the one listed first is skipped over. Empty strings are ignored.
+    /// Unlike a slice of string slices, the rest of the string is only scanned once, no matter how many
+    /// strings there are. Use [`matched`] to find out which one is next.
+    ///
+    /// Both `MultiPattern` and `&MultiPattern` can be used as patterns, and cloning it is cheap.
     ///
     /// # Examples
     ///
     /// ```
     /// # use slicer::AsSlicer;
-    /// let mut slicer = "This is a very long string that we just want to skip over entirely.".as_slicer();
-    /// slicer.skip_to_end();
-    /// assert_eq!(slicer.is_at_end(), true);
+    /// use slicer::patterns::MultiPattern;
+    ///
+    /// let keywords = MultiPattern::new(&["if", "else", "while", "return", "fn"]).unwrap();
+    /// let mut slicer = "x = 1; while x { return x }".as_slicer();
+    /// assert_eq!(slicer.slice_until(&keywords), Some("x = 1; "));
+    /// assert_eq!(keywords.matched(&slicer), Some(2));
+    /// slicer.skip_over(&keywords);
+    /// slicer.skip_until(&keywords);
+    /// assert_eq!(keywords.matched(&slicer), Some(3));
+    /// assert_eq!(slicer.cut_off(), Some("return x }"));
     /// ```
-    pub fn is_at_end(&self) -> bool {
-        self.byte_pos >= self.end_byte_pos()
+    ///
+    /// [`Pattern`]: ../trait.Pattern.html
+    /// [`matched`]: struct.MultiPattern.html#method.matched
+    #[cfg(feature = "aho-corasick")]
+    #[derive(Debug, Clone)]
+    pub struct MultiPattern {
+        automaton: ::aho_corasick::AhoCorasick,
+        //the index in the original list of each string given to the automaton, since empty strings are left out
+        indices: Vec<usize>
     }
-}
-
-impl<'str, T: Tracker> AsRef<str> for StrSlicer<'str, T> {
-    fn as_ref(&self) -> &str {
-        self.string
+    #[cfg(feature = "aho-corasick")]
+    impl MultiPattern {
+        /// Builds a pattern matching any of the given string slices.
+        ///
+        /// Returns an error if the strings are too big to be searched for all at once.
+        pub fn new<I, S>(strings: I) -> Result<Self, ::aho_corasick::BuildError>
+            where I: IntoIterator<Item = S>, S: AsRef<str>
+        {
+            let mut indices = Vec::new();
+            let mut non_empty = Vec::new();
+            for (index, string) in strings.into_iter().enumerate() {
+                if !string.as_ref().is_empty() {
+                    indices.push(index);
+                    non_empty.push(string);
+                }
+            }
+            let automaton = ::aho_corasick::AhoCorasick::builder()
+                .match_kind(::aho_corasick::MatchKind::LeftmostFirst)
+                .start_kind(::aho_corasick::StartKind::Both)
+                .build(non_empty.iter().map(|string| string.as_ref()))?;
+            Ok(Self {
+                automaton,
+                indices
+            })
+        }
+        /// Returns the index of the string that is next at the given slicer's position, in the order the strings were
+        /// given to [`new`], or `None` if none of them are next.
+        ///
+        /// [`new`]: struct.MultiPattern.html#method.new
+        pub fn matched<'str, T: Tracker>(&self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            self.find_next(slicer).map(|found| self.indices[found.pattern().as_usize()])
+        }
+        fn find_next<'str, T: Tracker>(&self, slicer: &StrSlicer<'str, T>) -> Option<::aho_corasick::Match> {
+            let cut_off = slicer.cut_off()?;
+            let input = ::aho_corasick::Input::new(cut_off).anchored(::aho_corasick::Anchored::Yes);
+            self.automaton.find(input)
+        }
     }
-}
-
-/// Used by `jump_oob_fail` and `jump_char_boundary_fail`
-//truncate `&str` to length at most equal to `max`,
-//return `true` if it were truncated, and the new str.
-//basically copied from the truncate_to_char_boundary function in libcore/str/mod.rs
-fn truncate_to_char_boundary(s: &str, mut max: usize) -> (bool, &str) {
-    if max >= s.len() {
-        (false, s)
-    } else {
-        while !s.is_char_boundary(max) {
-            max -= 1;
+    #[cfg(feature = "aho-corasick")]
+    impl Pattern for &MultiPattern {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            self.find_next(slicer).is_some()
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            let found = match slicer.cut_off() {
+                None => return, //return early, since the slicer is finished so there's nothing we can do
+                Some(cut_off) => self.automaton.find(cut_off)
+            };
+            match found {
+                //if none of the strings were found, simulate skipping until the end of the string
+                None => slicer.skip_to_end(),
+                Some(found) => {
+                    let byte_pos = slicer.byte_pos + found.start();
+                    unsafe {
+                        slicer.jump_to_unchecked(byte_pos);
+                    }
+                }
+            }
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            if let Some(len) = self.match_len(slicer) {
+                let byte_pos = slicer.byte_pos + len;
+                slicer.jump_to_unchecked(byte_pos);
+            }
+        }
+        fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            self.find_next(slicer).map(|found| found.len())
+        }
+    }
+    #[cfg(feature = "aho-corasick")]
+    impl Pattern for MultiPattern {
+        fn is_next<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> bool {
+            (&*self).is_next(slicer)
+        }
+        fn skip_until<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            (&*self).skip_until(slicer)
+        }
+        unsafe fn skip_over_unchecked<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            (&*self).skip_over_unchecked(slicer)
+        }
+        fn rskip_until_after<'str, T: Tracker>(&mut self, slicer: &mut StrSlicer<'str, T>) {
+            (&*self).rskip_until_after(slicer)
+        }
+        fn match_len<'str, T: Tracker>(&mut self, slicer: &StrSlicer<'str, T>) -> Option<usize> {
+            (&*self).match_len(slicer)
         }
-        (true, &s[..max])
     }
 }
 
-/// Function that panics for out-of-bound errors in [`StrSlicer::jump_to`]
-///
-/// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
-//basically copied from the slice_error_fail function in libcore/str/mod.rs
-#[inline(never)]
-#[cold]
-fn jump_oob_fail(string: &str, byte_pos: usize) -> ! {
-    const MAX_DISPLAY_LENGTH: usize = 256;
-    let (truncated, s_trunc) = truncate_to_char_boundary(string, MAX_DISPLAY_LENGTH);
-    let ellipsis = if truncated { "[...]" } else { "" };
-
-    panic!("byte index {} is out of bounds of `{}`{}", byte_pos, s_trunc, ellipsis);
-}
-
-/// Function that panics for jumping to a position that is not a UTF-8
-/// char boundary in [`StrSlicer::jump_to`]
-///
-/// [`StrSlicer::jump_to`]: struct.StrSlicer.html#method.jump_to
-//basically copied from the slice_error_fail function in libcore/str/mod.rs
-#[inline(never)]
-#[cold]
-fn jump_char_boundary_fail(string: &str, byte_pos: usize) -> ! {
-    const MAX_DISPLAY_LENGTH: usize = 256;
-    let (truncated, s_trunc) = truncate_to_char_boundary(string, MAX_DISPLAY_LENGTH);
-    let ellipsis = if truncated { "[...]" } else { "" };
+/// A module containing ready-made helpers for slicing tokens of common data formats.
+pub mod formats {
+    use std::char;
+    use std::error::Error;
+    use std::fmt;
+    use ::{StrSlicer, Tracker};
     
-    //find the start index of the character byte_pos is inside of
-    let mut char_start = byte_pos;
-    while !string.is_char_boundary(char_start) {
-        char_start -= 1;
+    /// The error returned by [`slice_json_string`].
+    ///
+    /// Each variant holds the byte index where the problem was found.
+    ///
+    /// [`slice_json_string`]: fn.slice_json_string.html
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum JsonStrError {
+        /// The slicer wasn't at a `"`.
+        MissingQuote { byte_pos: usize },
+        /// The string ended before the closing `"`.
+        Unterminated { byte_pos: usize },
+        /// A `\` wasn't followed by a valid escape sequence.
+        InvalidEscape { byte_pos: usize },
+        /// A control char appeared without being escaped.
+        ControlChar { byte_pos: usize }
     }
+    impl JsonStrError {
+        /// Returns the byte index where the problem was found.
+        pub fn byte_pos(&self) -> usize {
+            match *self {
+                JsonStrError::MissingQuote { byte_pos } |
+                JsonStrError::Unterminated { byte_pos } |
+                JsonStrError::InvalidEscape { byte_pos } |
+                JsonStrError::ControlChar { byte_pos } => byte_pos
+            }
+        }
+    }
+    impl fmt::Display for JsonStrError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let description = match *self {
+                JsonStrError::MissingQuote { .. } => "expected a JSON string",
+                JsonStrError::Unterminated { .. } => "unterminated JSON string",
+                JsonStrError::InvalidEscape { .. } => "invalid escape sequence in JSON string",
+                JsonStrError::ControlChar { .. } => "unescaped control character in JSON string"
+            };
+            write!(f, "{} at byte index {}", description, self.byte_pos())
+        }
+    }
+    impl Error for JsonStrError {}
     
-    //`char_start` must be less than len and a char boundary
-    let char = string[char_start..].chars().next().unwrap();
-    let char_byte_range = char_start..(char_start + char.len_utf8());
-    
-    panic!("byte index {} is not a char boundary; it is inside {:?} (bytes {:?}) of `{}`{}",
-           byte_pos, char, char_byte_range, s_trunc, ellipsis);
+    /// Slices a JSON string token, including its quotes, and returns its decoded contents.
+    ///
+    /// All of JSON's escape sequences are supported, including `\uXXXX` escapes that use surrogate pairs.
+    /// On error, the slicer is moved back to where it started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::formats::{slice_json_string, JsonStrError};
+    ///
+    /// let mut slicer = r#""plain" "say \"hi\"" "\u0041\ud83c\udf3a""#.as_slicer();
+    /// assert_eq!(slice_json_string(&mut slicer), Ok(String::from("plain")));
+    /// slicer.skip_whitespace();
+    /// assert_eq!(slice_json_string(&mut slicer), Ok(String::from("say \"hi\"")));
+    /// slicer.skip_whitespace();
+    /// assert_eq!(slice_json_string(&mut slicer), Ok(String::from("A🌺")));
+    ///
+    /// let mut slicer = r#""bad \x escape""#.as_slicer();
+    /// assert_eq!(slice_json_string(&mut slicer), Err(JsonStrError::InvalidEscape { byte_pos: 5 }));
+    /// assert_eq!(slicer.byte_pos(), 0);
+    ///
+    /// let mut slicer = r#""hello world""#.as_slicer();
+    /// slicer.set_scan_limit(Some(3));
+    /// assert_eq!(slice_json_string(&mut slicer), Ok(String::from("hello world")));
+    /// ```
+    pub fn slice_json_string<'str, T: Tracker>(slicer: &mut StrSlicer<'str, T>) -> Result<String, JsonStrError> {
+        let start_pos = slicer.save_pos();
+        let result = slice_json_string_inner(slicer);
+        if result.is_err() {
+            slicer.load_pos(start_pos);
+        }
+        result
+    }
+    fn slice_json_string_inner<'str, T: Tracker>(slicer: &mut StrSlicer<'str, T>) -> Result<String, JsonStrError> {
+        let start_pos = slicer.byte_pos();
+        if !slicer.skip_over('"') {
+            return Err(JsonStrError::MissingQuote { byte_pos: start_pos });
+        }
+        let mut string = String::new();
+        loop {
+            if let Some(chunk) = slicer.slice_until(|c: char| c == '"' || c == '\\' || c < '\u{20}') {
+                string.push_str(chunk);
+            }
+            let byte_pos = slicer.byte_pos();
+            match slicer.expect_char() {
+                Err(_) => return Err(JsonStrError::Unterminated { byte_pos: start_pos }),
+                Ok('"') => return Ok(string),
+                Ok('\\') => string.push(slice_json_escape(slicer).ok_or(JsonStrError::InvalidEscape { byte_pos })?),
+                //the scan limit stopped the search before any special char
+                Ok(c) if c >= '\u{20}' => string.push(c),
+                Ok(_) => return Err(JsonStrError::ControlChar { byte_pos })
+            }
+        }
+    }
+    /// Used by [`slice_json_string`] to decode an escape sequence, after its `\` was skipped over.
+    ///
+    /// [`slice_json_string`]: fn.slice_json_string.html
+    fn slice_json_escape<'str, T: Tracker>(slicer: &mut StrSlicer<'str, T>) -> Option<char> {
+        let decoded = match slicer.expect_char().ok()? {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let unit = slice_hex_unit(slicer)?;
+                match unit {
+                    //a high surrogate has to be followed by an escaped low surrogate
+                    0xD800..=0xDBFF => {
+                        if !slicer.skip_over("\\u") {
+                            return None;
+                        }
+                        let low = slice_hex_unit(slicer)?;
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return None;
+                        }
+                        char::from_u32(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00))?
+                    },
+                    _ => char::from_u32(unit)?
+                }
+            },
+            _ => return None
+        };
+        Some(decoded)
+    }
+    /// Used by [`slice_json_string`] to decode the 4 hex digits of a `\u` escape sequence.
+    ///
+    /// [`slice_json_string`]: fn.slice_json_string.html
+    fn slice_hex_unit<'str, T: Tracker>(slicer: &mut StrSlicer<'str, T>) -> Option<u32> {
+        let hex = slicer.slice_fixed(4)?;
+        if hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            u32::from_str_radix(hex, 16).ok()
+        } else {
+            None
+        }
+    }
 }
 
 /// A module containing various [`Tracker`] types.
 ///
 /// [`Tracker`]: trait.Tracker.html
 pub mod trackers {
-    use ::Tracker;
+    use std::cell::RefCell;
+    use ::{count_newlines, SnapshotTracker, Tracker};
     
     const NEWLINE: char = '\n';
     
@@ -919,9 +5250,8 @@ pub mod trackers {
             //if we're jumping forward, simply add up the newlines in the area we're jumping through
             if new_byte_pos > old_byte_pos {
                 
-                let mut newline_count = 0;
-                for (index, _) in string[old_byte_pos..new_byte_pos].match_indices(NEWLINE) {
-                    newline_count += 1;
+                let (newline_count, last_newline) = count_newlines(&string[old_byte_pos..new_byte_pos]);
+                if let Some(index) = last_newline {
                     self.line_byte_pos = index;
                 }
                 self.lines += newline_count;
@@ -936,18 +5266,16 @@ pub mod trackers {
                 
                 if diff > half_len_to_root {
                     
-                    let mut newline_count = 0;
-                    for (index, _) in string[0..new_byte_pos].match_indices(NEWLINE) {
-                        newline_count += 1;
+                    let (newline_count, last_newline) = count_newlines(&string[0..new_byte_pos]);
+                    if let Some(index) = last_newline {
                         self.line_byte_pos = index;
                     }
                     self.lines = newline_count;
                     
                 } else {
                     
-                    let mut newline_count = 0;
-                    for (index, _) in string[new_byte_pos..old_byte_pos].match_indices(NEWLINE) {
-                        newline_count += 1;
+                    let (newline_count, last_newline) = count_newlines(&string[new_byte_pos..old_byte_pos]);
+                    if let Some(index) = last_newline {
                         self.line_byte_pos = index;
                     }
                     self.lines -= newline_count;
@@ -956,5 +5284,256 @@ pub mod trackers {
             }
             
         }
+        fn reset(&mut self) {
+            self.lines = 0;
+            self.line_byte_pos = 0;
+        }
+    }
+    impl SnapshotTracker for LineTracker {
+        type Snapshot = Self;
+        fn snapshot(&self) -> Self::Snapshot {
+            self.clone()
+        }
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            *self = snapshot;
+        }
+    }
+    
+    /// A [`Tracker`] whose position is simply the slicer's current byte index.
+    ///
+    /// Useful for generic code that always goes through [`StrSlicer::tracker_pos`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::ByteOffsetTracker;
+    ///
+    /// let mut slicer = "🌺 Hibiscus".as_slicer_with_tracker(ByteOffsetTracker::new());
+    /// slicer.jump_to(4);
+    /// assert_eq!(slicer.tracker_pos(), slicer.byte_pos());
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), slicer.byte_pos());
+    /// slicer.jump_to(0);
+    /// assert_eq!(slicer.tracker_pos(), slicer.byte_pos());
+    /// ```
+    ///
+    /// [`Tracker`]: ../trait.Tracker.html
+    /// [`StrSlicer::tracker_pos`]: ../struct.StrSlicer.html#method.tracker_pos
+    #[derive(Debug, Clone)]
+    pub struct ByteOffsetTracker {
+        byte_pos: usize
+    }
+    impl ByteOffsetTracker {
+        pub fn new() -> Self {
+            Self {
+                byte_pos: 0
+            }
+        }
+    }
+    impl Default for ByteOffsetTracker {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl Tracker for ByteOffsetTracker {
+        type Pos = usize;
+        fn pos(&self) -> Self::Pos {
+            self.byte_pos
+        }
+        fn update(&mut self, _string: &str, _old_byte_pos: usize, new_byte_pos: usize) {
+            self.byte_pos = new_byte_pos;
+        }
+        fn reset(&mut self) {
+            self.byte_pos = 0;
+        }
+    }
+    impl SnapshotTracker for ByteOffsetTracker {
+        type Snapshot = usize;
+        fn snapshot(&self) -> Self::Snapshot {
+            self.byte_pos
+        }
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            self.byte_pos = snapshot;
+        }
+    }
+    
+    /// A [`Tracker`] that calls a function with the byte index of each newline the slicer moves forward over.
+    ///
+    /// This is forward-only: moving the slicer backwards doesn't call the function, and moving
+    /// forward again over the same area calls it again for the same newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::CallbackLineTracker;
+    ///
+    /// let mut newlines = Vec::new();
+    /// {
+    ///     let tracker = CallbackLineTracker::new(|byte_pos| newlines.push(byte_pos));
+    ///     let mut slicer = "a\nb\nc".as_slicer_with_tracker(tracker);
+    ///     while slicer.slice_line().is_some() {}
+    /// }
+    /// assert_eq!(newlines, [1, 3]);
+    /// ```
+    ///
+    /// [`Tracker`]: ../trait.Tracker.html
+    pub struct CallbackLineTracker<F: FnMut(usize)> {
+        callback: F
+    }
+    impl<F: FnMut(usize)> CallbackLineTracker<F> {
+        pub fn new(callback: F) -> Self {
+            Self {
+                callback
+            }
+        }
+    }
+    impl<F: FnMut(usize)> Tracker for CallbackLineTracker<F> {
+        type Pos = ();
+        fn pos(&self) -> Self::Pos {}
+        fn update(&mut self, string: &str, old_byte_pos: usize, new_byte_pos: usize) {
+            if new_byte_pos > old_byte_pos {
+                for (index, _) in string[old_byte_pos..new_byte_pos].match_indices(NEWLINE) {
+                    (self.callback)(old_byte_pos + index);
+                }
+            }
+        }
+    }
+    impl<F: FnMut(usize)> SnapshotTracker for CallbackLineTracker<F> {
+        type Snapshot = ();
+        fn snapshot(&self) -> Self::Snapshot {}
+        fn restore(&mut self, _snapshot: Self::Snapshot) {}
+    }
+    
+    /// A [`Tracker`] that counts how many times a given char occurs before the slicer's position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::CharCounterTracker;
+    ///
+    /// let mut slicer = "a,b,c".as_slicer_with_tracker(CharCounterTracker::new(','));
+    /// slicer.skip_until_after(',');
+    /// assert_eq!(slicer.tracker_pos(), 1);
+    /// slicer.skip_to_end();
+    /// assert_eq!(slicer.tracker_pos(), 2);
+    /// slicer.jump_to(0);
+    /// assert_eq!(slicer.tracker_pos(), 0);
+    /// ```
+    ///
+    /// [`Tracker`]: ../trait.Tracker.html
+    #[derive(Debug, Clone)]
+    pub struct CharCounterTracker {
+        target: char,
+        count: usize
+    }
+    impl CharCounterTracker {
+        pub fn new(target: char) -> Self {
+            Self {
+                target,
+                count: 0
+            }
+        }
+    }
+    impl Tracker for CharCounterTracker {
+        type Pos = usize;
+        fn pos(&self) -> Self::Pos {
+            self.count
+        }
+        fn update(&mut self, string: &str, old_byte_pos: usize, new_byte_pos: usize) {
+            if new_byte_pos > old_byte_pos {
+                self.count += string[old_byte_pos..new_byte_pos].matches(self.target).count();
+            } else if new_byte_pos < old_byte_pos {
+                self.count -= string[new_byte_pos..old_byte_pos].matches(self.target).count();
+            }
+        }
+        fn reset(&mut self) {
+            self.count = 0;
+        }
+    }
+    impl SnapshotTracker for CharCounterTracker {
+        type Snapshot = usize;
+        fn snapshot(&self) -> Self::Snapshot {
+            self.count
+        }
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            self.count = snapshot;
+        }
+    }
+    
+    /// A [`Tracker`] that wraps another tracker and caches its position, for trackers whose
+    /// [`pos`] is expensive to compute.
+    ///
+    /// The position is computed the first time it's asked for, and reused until the slicer moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicer::AsSlicer;
+    /// use slicer::trackers::{CachedTracker, LineTracker};
+    ///
+    /// let string = "a\nb\nc";
+    /// let mut cached = string.as_slicer_with_tracker(CachedTracker::new(LineTracker::new()));
+    /// let mut uncached = string.as_slicer_with_tracker(LineTracker::new());
+    /// for _ in 0..3 {
+    ///     assert_eq!(cached.tracker_pos(), uncached.tracker_pos());
+    ///     assert_eq!(cached.tracker_pos(), uncached.tracker_pos());
+    ///     cached.skip_line();
+    ///     uncached.skip_line();
+    /// }
+    /// ```
+    ///
+    /// [`Tracker`]: ../trait.Tracker.html
+    /// [`pos`]: ../trait.Tracker.html#tymethod.pos
+    #[derive(Debug, Clone)]
+    pub struct CachedTracker<T: Tracker> where T::Pos: Clone {
+        inner: T,
+        cached_pos: RefCell<Option<T::Pos>>
+    }
+    impl<T: Tracker> CachedTracker<T> where T::Pos: Clone {
+        pub fn new(inner: T) -> Self {
+            Self {
+                inner,
+                cached_pos: RefCell::new(None)
+            }
+        }
+        /// Returns a reference to the wrapped tracker.
+        pub fn inner(&self) -> &T {
+            &self.inner
+        }
+        /// Unwraps the wrapped tracker.
+        pub fn into_inner(self) -> T {
+            self.inner
+        }
+    }
+    impl<T: Tracker> Tracker for CachedTracker<T> where T::Pos: Clone {
+        type Pos = T::Pos;
+        fn pos(&self) -> Self::Pos {
+            self.cached_pos.borrow_mut()
+                .get_or_insert_with(|| self.inner.pos())
+                .clone()
+        }
+        fn update(&mut self, string: &str, old_byte_pos: usize, new_byte_pos: usize) {
+            if new_byte_pos != old_byte_pos {
+                *self.cached_pos.get_mut() = None;
+                self.inner.update(string, old_byte_pos, new_byte_pos);
+            }
+        }
+        fn reset(&mut self) {
+            *self.cached_pos.get_mut() = None;
+            self.inner.reset();
+        }
+    }
+    impl<T: SnapshotTracker> SnapshotTracker for CachedTracker<T> where T::Pos: Clone {
+        type Snapshot = T::Snapshot;
+        fn snapshot(&self) -> Self::Snapshot {
+            self.inner.snapshot()
+        }
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            *self.cached_pos.get_mut() = None;
+            self.inner.restore(snapshot);
+        }
     }
 }
\ No newline at end of file